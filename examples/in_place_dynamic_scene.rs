@@ -0,0 +1,79 @@
+//! This demonstrates how to hook a `DynamicScene` you spawn yourself,
+//! instead of spawning it with a `HookedDynamicSceneBundle`.
+//!
+//! Scenes spawned with `SceneSpawner::spawn_dynamic_sync` have no dedicated
+//! scene entity with a `SceneInstance` component, so `HookPlugin` can't know
+//! when to hook them. Instead, call `run_hook_on_instance` right after
+//! spawning the scene.
+use bevy::{prelude::*, scene::ScenePlugin};
+use bevy_scene_hook::{run_hook_on_instance, HookPlugin, SceneHook};
+
+#[derive(Component)]
+struct Pile;
+
+#[derive(Component)]
+struct Card;
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        HierarchyPlugin,
+        ScenePlugin,
+        HookPlugin,
+    ))
+    .add_systems(Startup, (setup, spawn_in_place).chain())
+    .add_systems(Update, show_hooked);
+    // `MinimalPlugins` would otherwise run the app forever.
+    app.update();
+}
+
+#[derive(Resource)]
+struct Table {
+    entity: Entity,
+    scene: Handle<DynamicScene>,
+}
+
+fn setup(
+    mut cmds: Commands,
+    mut scenes: ResMut<Assets<DynamicScene>>,
+    registry: Res<AppTypeRegistry>,
+) {
+    // A `DynamicScene` usually comes from a `.scn.ron` file, here we build it
+    // from a world, so that the example doesn't need any asset.
+    let mut world = World::new();
+    world.insert_resource(registry.clone());
+    world.spawn(Name::new("Pile"));
+    world.spawn(Name::new("Card"));
+    let scene = scenes.add(DynamicScene::from_world(&world));
+
+    let entity = cmds.spawn(Name::new("Table")).id();
+    cmds.insert_resource(Table { entity, scene });
+}
+
+fn spawn_in_place(world: &mut World) {
+    let Table { entity, scene } = world.remove_resource::<Table>().unwrap();
+
+    // 1. Write the scene into the world yourself.
+    let instance = world.resource_scope(|world, mut spawner: Mut<SceneSpawner>| {
+        spawner.spawn_dynamic_sync(world, &scene).unwrap()
+    });
+    // 2. Hook it, the table entity plays the role of the scene entity.
+    let hook = SceneHook::new(|entity, cmds| {
+        match entity.get().map(Name::as_str) {
+            Some("Pile") => cmds.insert(Pile),
+            Some("Card") => cmds.insert(Card),
+            _ => cmds,
+        };
+    });
+    let hooked = run_hook_on_instance(instance, entity, &hook, world);
+    // `spawn_dynamic_sync` spawns the scene immediately, so it is always ready.
+    assert!(hooked);
+}
+
+fn show_hooked(piles: Query<&Name, With<Pile>>, cards: Query<&Name, With<Card>>) {
+    for name in piles.iter().chain(&cards) {
+        println!("Hooked {name}");
+    }
+}
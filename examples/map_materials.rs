@@ -0,0 +1,34 @@
+//! This demonstrates how to replace the materials of a scene with
+//! `SceneHook::map_materials`.
+//!
+//! Run it with `cargo run --example map_materials --features pbr`.
+use bevy::prelude::*;
+use bevy_scene_hook::{HookPlugin, HookedSceneBundle, SceneHook};
+
+const SAMPLE: &str = "sample-scene.gltf#Scene0";
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, HookPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut cmds: Commands, server: Res<AssetServer>) {
+    cmds.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(5., 0., 0.).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+    cmds.spawn(DirectionalLightBundle {
+        transform: Transform::from_xyz(3., 2., 1.).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+
+    // The cube of the sample scene, but in glossy red.
+    let hook = SceneHook::map_materials(|material| StandardMaterial {
+        base_color: Color::RED,
+        perceptual_roughness: 0.1,
+        ..material.clone()
+    });
+    cmds.spawn(HookedSceneBundle::new(server.load(SAMPLE), hook));
+}
@@ -0,0 +1,82 @@
+//! Derive macros for `bevy-scene-hook`, please use the re-exports in the
+//! `bevy_scene_hook` crate instead of depending on this crate directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Fields, Lit, LitStr, Meta};
+
+/// Implement `SceneNode` and `FromStr` for a fieldless enum.
+///
+/// Each variant matches the entity `Name` equal to the variant's name, use
+/// `#[name = "..."]` on a variant to match a different name.
+#[proc_macro_derive(SceneNode, attributes(name))]
+pub fn derive_scene_node(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    scene_node(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn variant_name(variant: &syn::Variant) -> syn::Result<LitStr> {
+    let mut name = LitStr::new(&variant.ident.to_string(), variant.ident.span());
+    for attr in variant.attrs.iter().filter(|a| a.path().is_ident("name")) {
+        let Meta::NameValue(meta) = &attr.meta else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "expected `#[name = \"...\"]`",
+            ));
+        };
+        let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = &meta.value else {
+            return Err(syn::Error::new_spanned(
+                &meta.value,
+                "expected a string literal",
+            ));
+        };
+        name = lit.clone();
+    }
+    Ok(name)
+}
+
+fn scene_node(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`SceneNode` can only be derived on enums",
+        ));
+    };
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut names = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            let msg = "`SceneNode` variants must not have fields";
+            return Err(syn::Error::new_spanned(variant, msg));
+        }
+        variants.push(&variant.ident);
+        names.push(variant_name(variant)?);
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bevy_scene_hook::SceneNode for #ident #ty_generics #where_clause {
+            fn from_name(name: &str) -> ::core::option::Option<Self> {
+                match name {
+                    #( #names => ::core::option::Option::Some(Self::#variants), )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+            fn as_str(&self) -> &'static str {
+                match self {
+                    #( Self::#variants => #names, )*
+                }
+            }
+        }
+        impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ::bevy_scene_hook::UnknownSceneNode;
+
+            fn from_str(name: &str) -> ::core::result::Result<Self, Self::Err> {
+                <Self as ::bevy_scene_hook::SceneNode>::from_name(name)
+                    .ok_or(::bevy_scene_hook::UnknownSceneNode)
+            }
+        }
+    })
+}
@@ -0,0 +1,168 @@
+//! Build [`SceneHook`]s and [`reload::Hook`]s dispatching on entity names,
+//! see [`SceneHookBuilder`] and [`HookBuilder`].
+
+use std::borrow::Cow;
+
+use bevy::core::Name;
+#[cfg(feature = "reload")]
+use bevy::ecs::{entity::Entity, world::World};
+use bevy::ecs::{system::EntityCommands, world::EntityRef};
+use bevy::utils::HashMap;
+
+#[cfg(feature = "reload")]
+use crate::reload;
+use crate::SceneHook;
+
+type NameFn = dyn Fn(&mut EntityCommands) + Send + Sync + 'static;
+type UnmatchedFn = dyn Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static;
+#[cfg(feature = "reload")]
+type ReloadNameFn = dyn Fn(&mut EntityCommands, &World, Entity) + Send + Sync + 'static;
+#[cfg(feature = "reload")]
+type ReloadUnmatchedFn =
+    dyn Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static;
+
+/// Build a [`SceneHook`] running a different closure per entity [`Name`].
+///
+/// This replaces the typical `match entity.get::<Name>().map(Name::as_str)`
+/// block with a list of `on_name` calls.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::{SceneHook, SceneHookBuilder};
+/// # #[derive(Component)] struct Pile; #[derive(Component)] struct Card;
+/// # #[derive(Component)] struct Decoration;
+///
+/// let hook: SceneHook = SceneHookBuilder::new()
+///     .on_name("Pile", |cmds| { cmds.insert(Pile); })
+///     .on_name("Card", |cmds| { cmds.insert(Card); })
+///     .on_unmatched(|_entity, cmds| { cmds.insert(Decoration); })
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct SceneHookBuilder {
+    on_name: HashMap<Cow<'static, str>, Box<NameFn>>,
+    on_unmatched: Option<Box<UnmatchedFn>>,
+}
+impl SceneHookBuilder {
+    /// Create a builder which hook does nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `hook` on entities which [`Name`] is `name`.
+    ///
+    /// If called several times with the same `name`, only the last `hook` runs.
+    #[must_use]
+    pub fn on_name<F>(mut self, name: impl Into<Cow<'static, str>>, hook: F) -> Self
+    where
+        F: Fn(&mut EntityCommands) + Send + Sync + 'static,
+    {
+        self.on_name.insert(name.into(), Box::new(hook));
+        self
+    }
+
+    /// Run `hook` on entities with no [`Name`], or a `Name` not registered
+    /// with [`SceneHookBuilder::on_name`].
+    #[must_use]
+    pub fn on_unmatched<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        self.on_unmatched = Some(Box::new(hook));
+        self
+    }
+
+    /// Create the [`SceneHook`].
+    #[must_use]
+    pub fn build(self) -> SceneHook {
+        let Self { on_name, on_unmatched } = self;
+        SceneHook::new(move |entity, cmds| {
+            let name = entity.get::<Name>().map(Name::as_str);
+            match name.and_then(|name| on_name.get(name)) {
+                Some(hook) => hook(cmds),
+                None => {
+                    if let Some(on_unmatched) = &on_unmatched {
+                        on_unmatched(entity, cmds);
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Build a [`reload::Hook`] running a different closure per entity [`Name`].
+///
+/// This is the [`reload::Hook`] equivalent of [`SceneHookBuilder`], the
+/// closures also get the `&World` and the scene `Entity`, as [`reload::Hook::hook`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::reload;
+/// # #[derive(Component)] struct Pile; #[derive(Component)] struct Card(Entity);
+/// # #[derive(Component)] struct Decoration;
+///
+/// let hook: reload::Hook = reload::HookBuilder::new()
+///     .on_name("Pile", |cmds, _world, _root| { cmds.insert(Pile); })
+///     .on_name("Card", |cmds, _world, root| { cmds.insert(Card(root)); })
+///     .on_unmatched(|_entity, cmds, _world, _root| { cmds.insert(Decoration); })
+///     .build();
+/// ```
+#[cfg(feature = "reload")]
+#[derive(Default)]
+pub struct HookBuilder {
+    on_name: HashMap<Cow<'static, str>, Box<ReloadNameFn>>,
+    on_unmatched: Option<Box<ReloadUnmatchedFn>>,
+}
+#[cfg(feature = "reload")]
+impl HookBuilder {
+    /// Create a builder which hook does nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `hook` on entities which [`Name`] is `name`.
+    ///
+    /// If called several times with the same `name`, only the last `hook` runs.
+    #[must_use]
+    pub fn on_name<F>(mut self, name: impl Into<Cow<'static, str>>, hook: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World, Entity) + Send + Sync + 'static,
+    {
+        self.on_name.insert(name.into(), Box::new(hook));
+        self
+    }
+
+    /// Run `hook` on entities with no [`Name`], or a `Name` not registered
+    /// with [`HookBuilder::on_name`].
+    #[must_use]
+    pub fn on_unmatched<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static,
+    {
+        self.on_unmatched = Some(Box::new(hook));
+        self
+    }
+
+    /// Create the [`reload::Hook`].
+    #[must_use]
+    pub fn build(self) -> reload::Hook {
+        let Self { on_name, on_unmatched } = self;
+        reload::Hook::new(move |entity, cmds, world, root| {
+            let name = entity.get::<Name>().map(Name::as_str);
+            match name.and_then(|name| on_name.get(name)) {
+                Some(hook) => hook(cmds, world, root),
+                None => {
+                    if let Some(on_unmatched) = &on_unmatched {
+                        on_unmatched(entity, cmds, world, root);
+                    }
+                }
+            }
+        })
+    }
+}
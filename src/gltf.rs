@@ -0,0 +1,193 @@
+//! Hooks running on [`Gltf`] assets rather than on scenes, see [`GltfHook`].
+
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::{
+    bundle::Bundle,
+    component::Component,
+    entity::Entity,
+    prelude::{ReflectComponent, With, Without, World},
+    system::{Commands, EntityCommands, Query, Res},
+};
+use bevy::gltf::Gltf;
+use bevy::reflect::Reflect;
+use bevy::render::prelude::SpatialBundle;
+use bevy::transform::components::Transform;
+#[cfg(feature = "gltf-extras")]
+use bevy::{ecs::world::EntityRef, gltf::GltfExtras};
+
+use crate::SceneHook;
+
+type GltfHookFn = dyn Fn(&Gltf, &mut EntityCommands, &World) + Send + Sync + 'static;
+
+/// Add this with a `Handle<Gltf>` to an entity to run `hook` once the
+/// [`Gltf`] asset is loaded.
+///
+/// Unlike [`SceneHook`], this gives access to the whole
+/// `Gltf` asset: its named scenes, meshes, materials and animations. This is
+/// useful to prepare things based on the full file before spawning any of
+/// its scenes.
+///
+/// The entity gets the [`GltfHooked`] marker once the hook ran.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::{gltf::Gltf, prelude::*};
+/// use bevy_scene_hook::gltf::GltfHook;
+///
+/// #[derive(Component)]
+/// struct Animations(Vec<Handle<AnimationClip>>);
+///
+/// fn load_gltf(mut cmds: Commands, asset_server: Res<AssetServer>) {
+///     let gltf: Handle<Gltf> = asset_server.load("scene.glb");
+///     let hook = GltfHook::new(|gltf, cmds, _world| {
+///         cmds.insert(Animations(gltf.animations.clone()));
+///         if let Some(scene) = gltf.default_scene.clone() {
+///             cmds.insert(SceneBundle { scene, ..default() });
+///         }
+///     });
+///     cmds.spawn((gltf, hook));
+/// }
+/// ```
+#[derive(Component)]
+pub struct GltfHook {
+    hook: Box<GltfHookFn>,
+}
+impl GltfHook {
+    /// Run `hook` on the entity once its `Handle<Gltf>` is loaded.
+    ///
+    /// The `EntityCommands` are the commands of the entity with the `GltfHook`.
+    pub fn new<F>(hook: F) -> Self
+    where
+        F: Fn(&Gltf, &mut EntityCommands, &World) + Send + Sync + 'static,
+    {
+        Self { hook: Box::new(hook) }
+    }
+}
+
+/// Marker component for entities which [`GltfHook`] ran.
+///
+/// This is distinct from [`SceneHooked`](crate::SceneHooked), so that an
+/// entity can have both a `GltfHook` and a [`SceneHook`].
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[non_exhaustive]
+pub struct GltfHooked;
+
+/// Run once [`GltfHook`]s when their `Handle<Gltf>` is loaded.
+///
+/// Does nothing if the app has no `Assets<Gltf>`, for example without the
+/// `GltfPlugin`.
+pub fn run_gltf_hooks(
+    unloaded: Query<(Entity, &Handle<Gltf>, &GltfHook), Without<GltfHooked>>,
+    gltfs: Option<Res<Assets<Gltf>>>,
+    world: &World,
+    mut cmds: Commands,
+) {
+    let Some(gltfs) = gltfs else {
+        return;
+    };
+    for (entity, handle, hooked) in &unloaded {
+        let Some(gltf) = gltfs.get(handle) else {
+            continue;
+        };
+        let mut cmd = cmds.entity(entity);
+        cmd.insert(GltfHooked);
+        (hooked.hook)(gltf, &mut cmd, world);
+    }
+}
+
+/// Bundle a [`SceneHook`] with a `Handle<Gltf>`, to spawn and hook the
+/// default scene of the glTF file.
+///
+/// Create it with [`HookedSceneBundle::from_gltf`](crate::HookedSceneBundle::from_gltf).
+#[derive(Bundle)]
+#[allow(missing_docs /* field description is trivial */)]
+pub struct HookedGltfBundle {
+    pub hook: SceneHook,
+    pub gltf: Handle<Gltf>,
+    pub default_scene: SpawnDefaultScene,
+    pub spatial: SpatialBundle,
+}
+impl HookedGltfBundle {
+    /// Set the transform of the scene entity.
+    #[must_use]
+    pub const fn with_transform(mut self, transform: Transform) -> Self {
+        self.spatial.transform = transform;
+        self
+    }
+}
+
+/// Add this with a `Handle<Gltf>` to an entity to spawn the default scene
+/// of the [`Gltf`] asset once it is loaded.
+///
+/// [`spawn_gltf_scenes`] inserts the `Handle<Scene>` of [`Gltf::default_scene`],
+/// or of the first scene of the file if it has no default scene, then
+/// removes `SpawnDefaultScene`. The scene is then spawned by bevy, and hooked
+/// if the entity has a [`SceneHook`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SpawnDefaultScene;
+
+/// Insert the `Handle<Scene>` of [`SpawnDefaultScene`] entities once their
+/// `Handle<Gltf>` is loaded.
+///
+/// It should run before [`scene_spawner`](bevy::scene::scene_spawner), so
+/// that the scene is spawned in the same frame.
+pub fn spawn_gltf_scenes(
+    to_spawn: Query<(Entity, &Handle<Gltf>), With<SpawnDefaultScene>>,
+    gltfs: Option<Res<Assets<Gltf>>>,
+    mut cmds: Commands,
+) {
+    let Some(gltfs) = gltfs else {
+        return;
+    };
+    for (entity, handle) in &to_spawn {
+        let Some(gltf) = gltfs.get(handle) else {
+            continue;
+        };
+        let mut cmd = cmds.entity(entity);
+        cmd.remove::<SpawnDefaultScene>();
+        if let Some(scene) = gltf.default_scene.as_ref().or_else(|| gltf.scenes.first()) {
+            cmd.insert(scene.clone());
+        } else {
+            bevy::log::warn!("Can't spawn {handle:?}, the glTF file has no scenes");
+        }
+    }
+}
+
+/// Extension trait on [`EntityRef`] to read the glTF `extras` of scene entities.
+///
+/// glTF files can store custom properties as JSON in `extras`, for example
+/// Blender exports custom properties of objects as `extras`. Bevy adds them
+/// to spawned scene entities as a [`GltfExtras`] component.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::{gltf::GltfExtrasExt, SceneHook};
+///
+/// #[derive(Component)]
+/// struct Health(u64);
+///
+/// let hook = SceneHook::new(|entity, cmds| {
+///     let extras = entity.gltf_extras_json();
+///     if let Some(hp) = extras.and_then(|extras| extras["hp"].as_u64()) {
+///         cmds.insert(Health(hp));
+///     }
+/// });
+/// ```
+#[cfg(feature = "gltf-extras")]
+pub trait GltfExtrasExt {
+    /// The [`GltfExtras`] of this entity, parsed as JSON.
+    ///
+    /// `None` if the entity has no `GltfExtras` or they are not valid JSON.
+    fn gltf_extras_json(&self) -> Option<serde_json::Value>;
+}
+#[cfg(feature = "gltf-extras")]
+impl GltfExtrasExt for EntityRef<'_> {
+    fn gltf_extras_json(&self) -> Option<serde_json::Value> {
+        let extras = self.get::<GltfExtras>()?;
+        serde_json::from_str(&extras.value).ok()
+    }
+}
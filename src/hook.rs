@@ -2,20 +2,658 @@
 //!
 //! Please see the [`SceneHook`] documentation for detailed examples.
 
+#[cfg(feature = "pbr")]
+use bevy::asset::Assets;
+use bevy::asset::{Asset, AssetEvent, AssetId, AssetServer, Handle, LoadState};
+use bevy::core::Name;
 use bevy::ecs::{
-    component::Component,
-    entity::Entity,
-    prelude::{Without, World},
-    system::{Commands, EntityCommands, Query, Res},
-    world::EntityRef,
+    component::{Component, ComponentId, ComponentInfo},
+    entity::{Entity, EntityMapper, MapEntities},
+    prelude::{Added, AppTypeRegistry, ReflectComponent, With, Without, World},
+    prelude::{Event, EventReader},
+    query::QueryState,
+    reflect::ReflectMapEntities,
+    system::Resource,
+    system::SystemId,
+    system::{Command, CommandQueue, Commands, EntityCommand, EntityCommands},
+    system::{Local, Query, Res, ResMut},
+    world::{EntityRef, Mut},
 };
-use bevy::scene::{SceneInstance, SceneSpawner};
+use bevy::hierarchy::{Children, DespawnRecursiveExt, Parent};
+#[cfg(feature = "pbr")]
+use bevy::pbr::StandardMaterial;
+use bevy::reflect::{Reflect, TypeInfo};
+use bevy::scene::{InstanceId, Scene, SceneInstance, SceneSpawner};
+use bevy::tasks::{ComputeTaskPool, ParallelSlice, TaskPool};
+use bevy::time::Time;
+use bevy::utils::{Duration, HashMap, HashSet};
+
+use crate::SceneNode;
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::Poll;
+
+type HookFn = dyn Fn(&EntityRef, &mut EntityCommands, &World, Entity) -> ControlFlow<()>
+    + Send
+    + Sync
+    + 'static;
+type ExclusiveFn = dyn Fn(&mut World, Entity, &[Entity]) + Send + Sync + 'static;
+type OnLoadedFn = dyn Fn(&mut EntityCommands, &World) -> SceneVerdict + Send + Sync + 'static;
+type RootFn = dyn Fn(&mut EntityCommands, Entity) + Send + Sync + 'static;
+type DespawnFn = dyn Fn(Entity, &mut World) + Send + Sync + 'static;
+type FinishFn = dyn Fn(&mut World, Entity) + Send + Sync + 'static;
+type ResetFn = dyn Fn(Entity) + Send + Sync + 'static;
+
+/// How a [`SceneHook`] runs on the scene entities.
+///
+/// The hooks are `Arc`s so that [`SceneHook`] is `Clone`.
+#[derive(Clone)]
+enum HookKind {
+    /// Ran by [`run_hooks`] with a shared `&World`.
+    Commands(Arc<HookFn>),
+    /// Like `Commands`, but the scene is only hooked once its [`HookTask`]s complete.
+    Deferred(Arc<HookFn>),
+    /// Ran by [`run_exclusive_hooks`], with the list of scene entities.
+    ///
+    /// It is cloned out of the `World` before running.
+    Exclusive(Arc<ExclusiveFn>),
+    /// Does nothing, [`run_hooks`] marks the scene hooked without visiting its entities.
+    Noop,
+}
+
+/// The input of hook systems, see [`SceneHook::from_system`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneEntities {
+    /// The scene entity, with the [`SceneHook`] component.
+    pub root: Entity,
+    /// The entities of the scene instance, without `root`.
+    pub entities: Vec<Entity>,
+}
+
+/// Work started by a [`SceneHook::new_deferred`] hook, completing over multiple frames.
+pub trait HookTask: Send + Sync + 'static {
+    /// Poll the task, once per frame until it returns [`Poll::Ready`].
+    ///
+    /// `entity` is the commands of the entity the hook created this task for.
+    fn poll(&mut self, entity: &mut EntityCommands, world: &World) -> Poll<()>;
+}
+
+/// The [`HookTask`]s of a scene that are not complete yet, per hooked entity.
+///
+/// This is on the scene entity while [`poll_hook_tasks`] polls its tasks.
+#[derive(Component, Default)]
+pub struct HookTasks {
+    tasks: Vec<(Entity, Box<dyn HookTask>)>,
+    /// The [`SceneHookCompleted::entity_count`] to send once all tasks complete.
+    entity_count: usize,
+}
+impl HookTasks {
+    const fn new(entity_count: usize) -> Self {
+        Self { tasks: Vec::new(), entity_count }
+    }
+}
+
+/// The data available to a hook created with [`SceneHook::new_with_context`].
+#[non_exhaustive]
+pub struct HookContext<'w> {
+    /// The world, as it was before running the hook on any entity of the scene.
+    pub world: &'w World,
+    /// The `Entity` of the scene the hooked entity is part of.
+    pub root: Entity,
+    /// The parent of the hooked entity, `None` for the scene entity.
+    pub parent: Option<Entity>,
+    /// How many ancestors separate the hooked entity from the scene entity.
+    ///
+    /// This is `0` for the scene entity and `1` for the top-level entities of
+    /// the scene.
+    pub depth: u32,
+}
+impl<'w> HookContext<'w> {
+    fn new(world: &'w World, entity: &EntityRef, root: Entity) -> Self {
+        let parent = entity.get::<Parent>().filter(|_| entity.id() != root);
+        let parent = parent.map(Parent::get);
+        let mut depth = 0;
+        let mut current = entity.id();
+        while let (true, Some(parent)) = (current != root, world.get::<Parent>(current)) {
+            depth += 1;
+            current = parent.get();
+        }
+        Self { world, root, parent, depth }
+    }
+}
 
 /// Marker Component for scenes that were hooked.
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
 #[non_exhaustive]
 pub struct SceneHooked;
 
+/// Whether to keep a scene after it was hooked, see [`SceneHook::on_loaded`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SceneVerdict {
+    /// Keep the scene.
+    #[default]
+    Keep,
+    /// Despawn the scene entity recursively, with the reason for rejecting it.
+    Despawn(String),
+}
+
+/// Event sent when a scene is despawned due to [`SceneVerdict::Despawn`].
+#[derive(Event, Debug, Clone)]
+pub struct SceneHookRejected {
+    /// The scene entity, it is already despawned when reading this event.
+    pub root: Entity,
+    /// The reason given in [`SceneVerdict::Despawn`].
+    pub reason: String,
+}
+
+/// Event sent when a scene isn't ready before its [`SceneHook::with_timeout`] timeout.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SceneHookTimedOut {
+    /// The scene entity.
+    pub root: Entity,
+}
+
+/// Marker Component for scenes which entities exist, see [`mark_ready_instances`].
+///
+/// Use `Added<InstanceReady>` to react to spawned scenes, whether they have
+/// a hook or not, without checking [`SceneSpawner::instance_is_ready`] yourself.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[non_exhaustive]
+pub struct InstanceReady;
+
+/// How many entities the scene instance has, not counting the scene entity.
+///
+/// Inserted with [`InstanceReady`] by [`mark_ready_instances`], before the
+/// scene is hooked. With [`HookProgress`], this tells how much of a
+/// [`SceneHook::budgeted`] scene is hooked, for example to show a progress bar.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct HookTotalEntities(pub usize);
+
+/// Marker Component for scenes that timed out, see [`SceneHook::with_timeout`].
+///
+/// It is not removed if the scene gets ready later.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[non_exhaustive]
+pub struct HookTimedOut;
+
+/// Event sent when a [`SceneHook`] finished hooking a scene.
+///
+/// It is sent once per scene instance, the same frame [`SceneHooked`] is
+/// inserted, even for [`SceneHook::no_marker`] hooks.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SceneHookCompleted {
+    /// The scene entity.
+    pub root: Entity,
+    /// How many entities the scene instance has, not counting `root`.
+    pub entity_count: usize,
+}
+
+/// Insert this on a scene entity to run its [`SceneHook::rehookable`] hook again.
+///
+/// It is removed by [`rehook_requested_scenes`], and ignored if the hook is
+/// not `rehookable`.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct RehookRequest;
+
+/// Statistics on a hooked scene, added to the scene entity when it is hooked.
+///
+/// This is a quick way to check that a scene was loaded as expected.
+/// Entities are counted before the hook ran, components added by the hook
+/// are not considered.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct HookStats {
+    /// How many entities the scene instance has, not counting the scene entity.
+    pub total: usize,
+    /// How many of the scene instance entities have a [`Name`].
+    pub named: usize,
+}
+impl HookStats {
+    /// Count the `entities` of the scene `root`, `root` excluded.
+    fn count(world: &World, root: Entity, entities: &[Entity]) -> Self {
+        let entities = entities.iter().filter(|e| **e != root);
+        let (total, named) = entities.fold((0, 0), |(total, named), e| {
+            (
+                total + 1,
+                named + usize::from(world.get::<Name>(*e).is_some()),
+            )
+        });
+        Self { total, named }
+    }
+}
+
+pub use hook_instance::HookInstance;
+
+mod hook_instance {
+    // The `Reflect` impl generated by `reflect_value(PartialEq)` trips this lint.
+    #![allow(clippy::option_if_let_else)]
+
+    use bevy::ecs::{component::Component, reflect::ReflectComponent};
+    use bevy::reflect::Reflect;
+    use bevy::scene::InstanceId;
+
+    /// The scene instance a [`SceneHook`] ran on, added to the scene entity when it is hooked.
+    ///
+    /// Use it with [`SceneSpawner`] methods, for example to iterate again over
+    /// the scene entities with [`SceneSpawner::iter_instance_entities`].
+    /// Unlike [`SceneInstance`], it is also added by [`run_hook_on_instance`].
+    ///
+    /// It is reflected as an opaque value, since [`InstanceId`] is not reflected.
+    ///
+    /// [`SceneHook`]: crate::SceneHook
+    /// [`SceneSpawner`]: bevy::scene::SceneSpawner
+    /// [`SceneSpawner::iter_instance_entities`]: bevy::scene::SceneSpawner::iter_instance_entities
+    /// [`SceneInstance`]: bevy::scene::SceneInstance
+    /// [`run_hook_on_instance`]: crate::run_hook_on_instance
+    #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+    #[reflect_value(Component, Debug, PartialEq)]
+    pub struct HookInstance(pub InstanceId);
+}
+
+/// Event sent for each entity a [`SceneHook`] runs on, before running the hook.
+#[derive(Event, Debug, Clone)]
+pub struct HookedEntity {
+    /// The scene entity.
+    pub root: Entity,
+    /// The hooked entity, part of the `root` scene.
+    pub entity: Entity,
+    /// The [`Name`] of the hooked entity, if it has one.
+    pub name: Option<Name>,
+}
+impl HookedEntity {
+    fn new(root: Entity, entity: &EntityRef) -> Self {
+        let name = entity.get::<Name>().cloned();
+        Self { root, entity: entity.id(), name }
+    }
+}
+
+/// How many entities of a [`SceneHook::budgeted`] scene were hooked so far.
+///
+/// It is added to the scene entity, and removed once the scene is hooked.
+/// Compare it to [`HookTotalEntities`] to know the share of hooked entities.
+///
+/// # Resuming hooks
+///
+/// `HookProgress` is the cursor [`run_hooks`] resumes from, for all hooks ran
+/// by `run_hooks`, not only budgeted ones. The scene entities are always
+/// visited in the same order, the scene entity last, so `next` is a stable
+/// index into them. Insert it yourself to skip the entities before `next`:
+///
+/// - On a scene not hooked yet, to skip entities you already processed.
+///   The [`SceneHook::root`] hook still runs and [`HookStats`] is still
+///   inserted, they only depend on the scene, not on the cursor.
+/// - With a [`RehookRequest`], to run a [`SceneHook::rehookable`] hook again
+///   only on the entities from `next` on. The `root` hook doesn't run again.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HookProgress {
+    /// The index of the next scene entity to hook.
+    pub next: usize,
+}
+
+/// Log of the entities hooked by [`SceneHook`]s, see [`HookPlugin::with_logging`].
+///
+/// This is mostly useful in tests, to check which entities a hook ran on.
+/// [`log_hooked_entities`] fills it from [`HookedEntity`] events.
+///
+/// [`HookPlugin::with_logging`]: crate::HookPlugin::with_logging
+#[derive(Resource, Debug, Default)]
+pub struct HookLog {
+    /// Keep the entries of previous frames. Otherwise, the log only holds
+    /// the entities hooked in the last frame. `false` by default.
+    pub accumulate: bool,
+    entries: Vec<HookedEntity>,
+}
+impl HookLog {
+    /// The hooked entities, in the order the hooks ran on them.
+    #[must_use]
+    pub fn entries(&self) -> &[HookedEntity] {
+        &self.entries
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Record [`HookedEntity`] events in the [`HookLog`].
+pub fn log_hooked_entities(mut events: EventReader<HookedEntity>, mut log: ResMut<HookLog>) {
+    if !log.accumulate {
+        log.entries.clear();
+    }
+    log.entries.extend(events.read().cloned());
+}
+
+/// The components a [`SceneHook`] inserted on an entity.
+///
+/// Only added when the hook was created with [`SceneHook::track_inserted`].
+/// Use [`RemoveHookInsertedExt::remove_hook_inserted`] to remove them.
+///
+/// Components this crate inserts itself, such as [`HookRoot`] or [`HookStats`],
+/// are not listed.
+#[derive(Component, Debug, Default, Clone)]
+pub struct HookInserted(Vec<ComponentId>);
+impl HookInserted {
+    /// The [`ComponentId`]s of the components inserted by the hook.
+    #[must_use]
+    pub fn components(&self) -> &[ComponentId] {
+        &self.0
+    }
+}
+
+/// The scene entity of a hooked scene entity.
+///
+/// Inserted by [`run_hooks`] and [`run_exclusive_hooks`] on all the entities
+/// a [`SceneHook`] visits, except the scene entity itself.
+/// See [`scene_root`] to read it from a `&World`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct HookRoot(pub Entity);
+impl MapEntities for HookRoot {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// The scene entity `child` was spawned with, if `child` was hooked.
+///
+/// This is useful to find which scene an entity belongs to, for example
+/// after a raycast, without walking the [`Parent`] hierarchy.
+#[must_use]
+pub fn scene_root(world: &World, child: Entity) -> Option<Entity> {
+    world.get::<HookRoot>(child).map(|root| root.0)
+}
+
+/// The components recorded for [`SceneHook::templated`] hooks, per scene asset.
+#[derive(Resource, Default)]
+pub struct HookTemplates(HashMap<AssetId<Scene>, Option<Template>>);
+
+/// The reflected components inserted on each scene entity, in the order of
+/// [`SceneHook::entities`], without the scene entity.
+type Template = Vec<Vec<(ReflectComponent, Box<dyn Reflect>)>>;
+
+impl HookTemplates {
+    /// Whether `scene` has a template that can be used.
+    #[must_use]
+    pub fn contains(&self, scene: AssetId<Scene>) -> bool {
+        matches!(self.0.get(&scene), Some(Some(_)))
+    }
+
+    /// Forget the template of `scene`, it is recorded again next time a
+    /// templated hook runs on it.
+    pub fn remove(&mut self, scene: AssetId<Scene>) {
+        self.0.remove(&scene);
+    }
+
+    /// Forget all templates.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Whether to record a template for `scene`, `false` if it was already
+    /// recorded, or if it failed.
+    fn must_record(world: &World, scene: AssetId<Scene>) -> bool {
+        let templates = world.get_resource::<Self>();
+        templates.map_or(true, |templates| !templates.0.contains_key(&scene))
+    }
+
+    /// Whether to use the template of `scene` for an instance with `count` entities.
+    fn can_apply(world: &World, scene: AssetId<Scene>, count: usize) -> bool {
+        let templates = world.get_resource::<Self>();
+        let template = templates.and_then(|templates| templates.0.get(&scene));
+        matches!(template, Some(Some(template)) if template.len() == count)
+    }
+}
+
+/// [`Command`] recording the template of a [`SceneHook::templated`] hook.
+///
+/// Like [`RecordInserted`], it must be added after the hook ran.
+struct RecordTemplate {
+    scene: AssetId<Scene>,
+    entities: Vec<Entity>,
+    before: Vec<Vec<ComponentId>>,
+}
+impl RecordTemplate {
+    /// The template, `None` if an inserted component can't be reflected.
+    fn record(&self, world: &World) -> Option<Template> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut template = Template::with_capacity(self.entities.len());
+        for (entity, before) in self.entities.iter().zip(&self.before) {
+            let Some(entity) = world.get_entity(*entity) else {
+                template.push(Vec::new());
+                continue;
+            };
+            let mut inserted = Vec::new();
+            for component in entity.archetype().components() {
+                let info = world.components().get_info(component);
+                // Components managed by this crate are added by `ApplyTemplate` itself.
+                let managed = [TypeId::of::<HookRoot>(), TypeId::of::<HookInserted>()];
+                let type_id = info.and_then(ComponentInfo::type_id);
+                if before.contains(&component) || type_id.is_some_and(|t| managed.contains(&t)) {
+                    continue;
+                }
+                let reflect =
+                    type_id.and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id));
+                let value = reflect.and_then(|reflect| reflect.reflect(entity));
+                let (Some(reflect), Some(value)) = (reflect, value) else {
+                    let name = info.map_or("<unknown>", ComponentInfo::name);
+                    bevy::log::warn!(
+                        "Can't record a template for scene {:?}: the hook inserts `{name}`, \
+                        which doesn't `#[reflect(Component)]`",
+                        self.scene
+                    );
+                    return None;
+                };
+                inserted.push((reflect.clone(), value.clone_value()));
+            }
+            template.push(inserted);
+        }
+        Some(template)
+    }
+}
+impl Command for RecordTemplate {
+    fn apply(self, world: &mut World) {
+        let template = self.record(world);
+        let mut templates = world.get_resource_or_insert_with(HookTemplates::default);
+        templates.0.insert(self.scene, template);
+    }
+}
+
+/// [`Command`] inserting the template of `scene` on the entities of an instance.
+///
+/// This also inserts the [`HookRoot`] of the instance, which is not part of the template.
+struct ApplyTemplate {
+    scene: AssetId<Scene>,
+    root: Entity,
+    entities: Vec<Entity>,
+}
+impl Command for ApplyTemplate {
+    fn apply(self, world: &mut World) {
+        world.resource_scope(|world, templates: Mut<HookTemplates>| {
+            let Some(Some(template)) = templates.0.get(&self.scene) else {
+                return;
+            };
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let registry = registry.read();
+            for (entity, components) in self.entities.iter().zip(template) {
+                let Some(mut entity) = world.get_entity_mut(*entity) else {
+                    continue;
+                };
+                entity.insert(HookRoot(self.root));
+                for (reflect, value) in components {
+                    reflect.insert(&mut entity, &**value, &registry);
+                }
+            }
+        });
+    }
+}
+
+/// Whether `component` is inserted by this crate rather than by hooks.
+///
+/// Such components are never listed in [`HookInserted`].
+fn is_bookkeeping(world: &World, component: ComponentId) -> bool {
+    let bookkeeping = [
+        TypeId::of::<HookRoot>(),
+        TypeId::of::<HookInserted>(),
+        TypeId::of::<HookStats>(),
+        TypeId::of::<HookInstance>(),
+        TypeId::of::<HookTasks>(),
+        TypeId::of::<HookProgress>(),
+        TypeId::of::<SceneHooked>(),
+    ];
+    let info = world.components().get_info(component);
+    let type_id = info.and_then(ComponentInfo::type_id);
+    type_id.is_some_and(|type_id| bookkeeping.contains(&type_id))
+}
+
+/// [`EntityCommand`] recording which components were inserted by a hook.
+///
+/// It must be added **after** the hook ran, so that it is applied after the
+/// hook's own commands. The components are added to the existing
+/// [`HookInserted`], if any, for example when a [`SceneHook::rehookable`]
+/// hook runs again.
+struct RecordInserted {
+    before: Vec<ComponentId>,
+    /// Whether the `Name` was inserted by [`SceneHook::with_root_name`], not by the hook.
+    root_name: bool,
+}
+impl EntityCommand for RecordInserted {
+    fn apply(self, id: Entity, world: &mut World) {
+        let Some(entity) = world.get_entity(id) else {
+            return;
+        };
+        let name = world.component_id::<Name>().filter(|_| self.root_name);
+        let inserted: Vec<_> = entity
+            .archetype()
+            .components()
+            .filter(|c| !self.before.contains(c) && Some(*c) != name)
+            .filter(|c| !is_bookkeeping(world, *c))
+            .collect();
+        let mut entity = world.entity_mut(id);
+        if let Some(mut recorded) = entity.get_mut::<HookInserted>() {
+            for component in inserted {
+                if !recorded.0.contains(&component) {
+                    recorded.0.push(component);
+                }
+            }
+        } else {
+            entity.insert(HookInserted(inserted));
+        }
+    }
+}
+
+fn remove_hook_inserted(id: Entity, world: &mut World) {
+    let Some(HookInserted(inserted)) = world.get_entity_mut(id).and_then(|mut e| e.take()) else {
+        return;
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for component in inserted {
+        let info = world.components().get_info(component);
+        let reflect = info
+            .and_then(ComponentInfo::type_id)
+            .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id));
+        let Some(reflect) = reflect else {
+            let name = info.map_or("<unknown>", |info| info.name());
+            bevy::log::warn!(
+                "Can't remove hook-inserted `{name}`: it doesn't `#[reflect(Component)]`"
+            );
+            continue;
+        };
+        reflect.remove(&mut world.entity_mut(id));
+    }
+}
+
+/// Extension trait on [`EntityCommands`] to undo a [`SceneHook`].
+pub trait RemoveHookInsertedExt {
+    /// Remove all components listed in this entity's [`HookInserted`].
+    ///
+    /// This only works for components registered in the [`AppTypeRegistry`]
+    /// with `#[reflect(Component)]`, a warning is logged for other components.
+    fn remove_hook_inserted(&mut self) -> &mut Self;
+}
+impl RemoveHookInsertedExt for EntityCommands<'_> {
+    fn remove_hook_inserted(&mut self) -> &mut Self {
+        self.add(remove_hook_inserted)
+    }
+}
+
+fn despawn_hooked(root: Entity, world: &mut World) {
+    let Some(root_entity) = world.get_entity(root) else {
+        return;
+    };
+    let on_despawn = root_entity
+        .get::<SceneHook>()
+        .and_then(|h| h.on_despawn.clone());
+    let instance = root_entity.get::<SceneInstance>().map(|i| **i);
+    if let (Some(on_despawn), Some(instance)) = (on_despawn, instance) {
+        let spawner = world.resource::<SceneSpawner>();
+        let mut entities: Vec<_> = spawner.iter_instance_entities(instance).collect();
+        entities.push(root);
+        for entity in entities {
+            if world.get_entity(entity).is_some() {
+                on_despawn(entity, world);
+            }
+        }
+    }
+    if let Some(root) = world.get_entity_mut(root) {
+        root.despawn_recursive();
+    }
+}
+
+/// Extension trait on [`EntityCommands`] to despawn a hooked scene.
+pub trait DespawnHookedExt {
+    /// Run the [`SceneHook::with_despawn`] cleanup on each entity of this
+    /// scene, then despawn this scene entity recursively.
+    ///
+    /// Without a `with_despawn` cleanup, this is the same as `despawn_recursive`.
+    fn despawn_hooked(&mut self);
+}
+impl DespawnHookedExt for EntityCommands<'_> {
+    fn despawn_hooked(&mut self) {
+        self.add(despawn_hooked);
+    }
+}
+
+/// Extension trait on [`EntityRef`] to read the children of scene entities.
+///
+/// The scene hierarchy is already spawned when hooks run, so the [`Children`]
+/// of hooked entities are available, including the top-level scene entities
+/// as children of the scene root.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::{HookChildrenExt, SceneHook};
+///
+/// #[derive(Component)]
+/// struct WeaponSlots(usize);
+///
+/// let hook = SceneHook::new(|entity, cmds| {
+///     if entity.get::<Name>().map(Name::as_str) == Some("Rig") {
+///         cmds.insert(WeaponSlots(entity.children().len()));
+///     }
+/// });
+/// ```
+pub trait HookChildrenExt {
+    /// The [`Children`] of this entity, empty if it has none.
+    fn children(&self) -> &[Entity];
+}
+impl HookChildrenExt for EntityRef<'_> {
+    fn children(&self) -> &[Entity] {
+        self.get::<Children>().map_or(&[], |children| children)
+    }
+}
+
 /// Add this as a component to any entity to run `hook`
 /// when the scene is loaded.
 ///
@@ -27,8 +665,29 @@ pub struct SceneHooked;
 ///
 /// # Access to `World`
 ///
-/// A variant of `SceneHook` exists with access to the scene `Entity` and the `&World`,
-/// check [`crate::reload::Hook`] if you need such features.
+/// Use [`SceneHook::new_with_world`] to access the scene `Entity` and the `&World`.
+#[cfg_attr(
+    feature = "reload",
+    doc = "Check [`crate::reload::Hook`] if you also need reloading."
+)]
+#[cfg_attr(
+    not(feature = "reload"),
+    doc = "Check `reload::Hook`, with the `reload` feature, if you also need reloading."
+)]
+///
+/// # Removing components
+///
+/// Hooks can remove components with `cmds.remove::<C>()`. The removal is
+/// applied after the hook ran on all scene entities, so the `EntityRef` of
+/// hooks, including hooks chained with [`SceneHook::and_then`], still has
+/// the removed components. Use [`SceneHook::strip`] to remove a component
+/// from all scene entities.
+///
+/// # Nested scenes
+///
+/// Hooks can insert a [`HookedSceneBundle`](crate::HookedSceneBundle) on a scene entity to spawn a
+/// scene inside the scene. The nested scene is spawned and hooked on a later
+/// frame, once its `SceneInstance` is ready, like any other hooked scene.
 ///
 /// # Example
 ///
@@ -61,9 +720,28 @@ pub struct SceneHooked;
 ///     });
 /// }
 /// ```
-#[derive(Component)]
+#[derive(Component, Clone)]
+#[allow(clippy::struct_excessive_bools /* independent builder options */)]
 pub struct SceneHook {
-    hook: Box<dyn Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static>,
+    hook: HookKind,
+    track_inserted: bool,
+    marker: bool,
+    stable_order: bool,
+    parallel: bool,
+    rehookable: bool,
+    shallow: bool,
+    templated: bool,
+    on_loaded: Option<Arc<OnLoadedFn>>,
+    root: Option<Arc<RootFn>>,
+    on_despawn: Option<Arc<DespawnFn>>,
+    world_finish: Option<Arc<FinishFn>>,
+    /// Drop the per-scene state of the hook, see [`SceneHook::new_mut`].
+    reset: Option<Arc<ResetFn>>,
+    root_name: Option<Name>,
+    timeout: Option<Duration>,
+    fallback: Option<Handle<Scene>>,
+    gate: Option<TypeId>,
+    budget: Option<usize>,
 }
 impl SceneHook {
     /// Add a hook to a scene, to run for each entities when the scene is
@@ -74,8 +752,9 @@ impl SceneHook {
     ///
     /// # Access to `World`
     ///
-    /// A variant of `SceneHook` exists with access to the scene `Entity` and the `&World`,
-    /// check [`crate::reload::Hook`] if you need such features.
+    /// Use [`SceneHook::new_with_world`] to access the scene `Entity` and the `&World`.
+    /// Use [`SceneHook::new_with_res`] to read a resource, rather than cloning it
+    /// into the closure as in the following example.
     ///
     /// # Example
     ///
@@ -84,7 +763,7 @@ impl SceneHook {
     /// #   world::EntityRef, component::Component,
     /// #   system::{Commands, Res, Resource, EntityCommands}
     /// # };
-    /// # use bevy::asset::{AssetServer, Handle};
+    /// # use bevy::asset::{Asset, AssetEvent, AssetServer, Handle};
     /// # use bevy::utils::default;
     /// # use bevy::scene::{Scene, SceneBundle};
     /// use bevy_scene_hook::{SceneHook, HookedSceneBundle};
@@ -103,28 +782,2106 @@ impl SceneHook {
     /// }
     /// ```
     pub fn new<F: Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static>(hook: F) -> Self {
-        Self { hook: Box::new(hook) }
+        Self::new_with_world(move |entity, cmds, _, _| hook(entity, cmds))
     }
-}
 
-/// Run once [`SceneHook`]s added to [`SceneBundle`](crate::SceneBundle) or
-/// [`DynamicSceneBundle`](crate::DynamicSceneBundle) when the scenes are loaded.
-pub fn run_hooks(
-    unloaded_instances: Query<(Entity, &SceneInstance, &SceneHook), Without<SceneHooked>>,
-    scene_manager: Res<SceneSpawner>,
-    world: &World,
-    mut cmds: Commands,
-) {
-    for (entity, instance, hooked) in unloaded_instances.iter() {
-        if scene_manager.instance_is_ready(**instance) {
-            cmds.entity(entity).insert(SceneHooked);
-        }
-        let entities = scene_manager
-            .iter_instance_entities(**instance)
-            .chain(std::iter::once(entity));
-        for entity_ref in entities.filter_map(|e| world.get_entity(e)) {
-            let mut cmd = cmds.entity(entity_ref.id());
-            (hooked.hook)(&entity_ref, &mut cmd);
+    /// Add a hook reading the `R` resource.
+    ///
+    /// Unlike [`SceneHook::new_with_resource`], this runs in [`run_hooks`] and
+    /// can't mutate `R`. To read several resources, use [`SceneHook::new_with_world`].
+    ///
+    /// # Panics
+    ///
+    /// When running the hook if the `R` resource doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Deck(Handle<Scene>);
+    /// #[derive(Resource)]
+    /// struct DeckAssets { player: Handle<Scene>, oppo: Handle<Scene> }
+    ///
+    /// let hook = SceneHook::new_with_res(|entity, cmds, decks: &DeckAssets| {
+    ///     match entity.get::<Name>().map(Name::as_str) {
+    ///         Some("PlayerDeck") => cmds.insert(Deck(decks.player.clone())),
+    ///         Some("OppoDeck") => cmds.insert(Deck(decks.oppo.clone())),
+    ///         _ => cmds,
+    ///     };
+    /// });
+    /// ```
+    pub fn new_with_res<R, F>(hook: F) -> Self
+    where
+        R: Resource,
+        F: Fn(&EntityRef, &mut EntityCommands, &R) + Send + Sync + 'static,
+    {
+        Self::new_with_world(move |entity, cmds, world, _| {
+            hook(entity, cmds, world.resource::<R>());
+        })
+    }
+
+    /// Add a hook with access to the [`AssetServer`], to load assets related
+    /// to the hooked entities.
+    ///
+    /// This is [`SceneHook::new_with_res`] for the `AssetServer`.
+    ///
+    /// # Panics
+    ///
+    /// When running the hook if there is no `AssetServer`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// #[derive(Component)]
+    /// struct Decal(Handle<Image>);
+    ///
+    /// let hook = SceneHook::new_with_assets(|entity, cmds, assets| {
+    ///     if let Some(name) = entity.get::<Name>() {
+    ///         cmds.insert(Decal(assets.load(format!("decals/{name}.png"))));
+    ///     }
+    /// });
+    /// ```
+    pub fn new_with_assets<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands, &AssetServer) + Send + Sync + 'static,
+    {
+        Self::new_with_res(hook)
+    }
+
+    /// Add a hook with access to the hooked entity's place in the scene hierarchy.
+    ///
+    /// Like [`SceneHook::new_with_world`], but `hook` receives a [`HookContext`],
+    /// with the parent and depth of the hooked entity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Room;
+    ///
+    /// // Top-level nodes of the scene are rooms.
+    /// let hook = SceneHook::new_with_context(|_, cmds, ctx| {
+    ///     if ctx.depth == 1 {
+    ///         cmds.insert(Room);
+    ///     }
+    /// });
+    /// ```
+    pub fn new_with_context<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands, &HookContext) + Send + Sync + 'static,
+    {
+        Self::new_with_world(move |entity, cmds, world, root| {
+            hook(entity, cmds, &HookContext::new(world, entity, root));
+        })
+    }
+
+    /// Add a hook that can mutate its own state, for example to count entities.
+    ///
+    /// Like [`SceneHook::new`], but `new_hook` creates a `FnMut` hook for each
+    /// scene instance, so that its state persists across the entities of the
+    /// scene, but is not shared with other instances, even when the
+    /// `SceneHook` is cloned, as with
+    /// [`HookAppExt::add_scene_hook`](crate::HookAppExt::add_scene_hook).
+    ///
+    /// The hook runs on entities in [`SceneSpawner::iter_instance_entities`]
+    /// order (or the [`SceneHook::stable_order`] order), then on the scene entity.
+    /// It is dropped once the hook is done with the scene, whether or not it
+    /// ran on the scene entity, so a [`SceneHook::rehookable`] hook starts
+    /// with a fresh state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy::ecs::system::EntityCommands;
+    /// use bevy_scene_hook::SceneHook;
+    /// #[derive(Component)]
+    /// struct Pickup(usize);
+    ///
+    /// let hook = SceneHook::new_mut(|| {
+    ///     let mut pickup_count = 0;
+    ///     move |entity: &EntityRef, cmds: &mut EntityCommands| {
+    ///         if entity.get::<Name>().map(Name::as_str) == Some("Pickup") {
+    ///             cmds.insert(Pickup(pickup_count));
+    ///             pickup_count += 1;
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn new_mut<F, H>(new_hook: F) -> Self
+    where
+        F: Fn() -> H + Send + Sync + 'static,
+        H: FnMut(&EntityRef, &mut EntityCommands) + Send + 'static,
+    {
+        let hooks = Arc::new(Mutex::new(HashMap::<Entity, H>::new()));
+        let reset_hooks = Arc::clone(&hooks);
+        let mut hook = Self::new_with_world(move |entity, cmds, _, root| {
+            let mut hooks = hooks.lock().unwrap_or_else(PoisonError::into_inner);
+            hooks.entry(root).or_insert_with(&new_hook)(entity, cmds);
+        });
+        hook.reset = Some(Arc::new(move |root| {
+            reset_hooks
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(&root);
+        }));
+        hook
+    }
+
+    /// Add a hook with read access to the `World` and the scene `Entity`.
+    ///
+    /// Like [`SceneHook::new`], but `hook` also receives:
+    /// - `&World`: The world, useful to read assets or resources.
+    /// - `Entity`: The `Entity` of the scene the hooked entity is part of.
+    ///
+    /// The `World` is the same for all entities of the scene: it reflects the
+    /// state of the world **before** the hook ran. Components added by the
+    /// hook on other entities of the scene are not visible, since the
+    /// `EntityCommands` are only applied after the hook ran on all entities.
+    #[cfg_attr(
+        feature = "reload",
+        doc = "This is the same as with [`crate::reload::Hook`]."
+    )]
+    #[cfg_attr(
+        not(feature = "reload"),
+        doc = "This is the same as with `reload::Hook`."
+    )]
+    pub fn new_with_world<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static,
+    {
+        Self::from_kind(HookKind::Commands(Arc::new(
+            move |entity, cmds, world, root| {
+                hook(entity, cmds, world, root);
+                ControlFlow::Continue(())
+            },
+        )))
+    }
+
+    /// Add a hook that can stop early, skipping the remaining scene entities.
+    ///
+    /// Like [`SceneHook::new`], but when `hook` returns [`ControlFlow::Break`],
+    /// it won't run on the remaining entities of the scene. This is useful to
+    /// avoid iterating over large scenes once you found what you were looking for.
+    ///
+    /// Note that the scene is still marked as [`SceneHooked`] when the hook
+    /// breaks early, so it will not run again on the remaining entities.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::ops::ControlFlow;
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct LevelConfig;
+    ///
+    /// let hook = SceneHook::new_flow(|entity, cmds| {
+    ///     if entity.get::<Name>().map(Name::as_str) == Some("__config__") {
+    ///         cmds.insert(LevelConfig);
+    ///         return ControlFlow::Break(());
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// });
+    /// ```
+    pub fn new_flow<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands) -> ControlFlow<()> + Send + Sync + 'static,
+    {
+        Self::from_kind(HookKind::Commands(Arc::new(move |entity, cmds, _, _| {
+            hook(entity, cmds)
+        })))
+    }
+
+    /// Add a hook which work may complete over multiple frames.
+    ///
+    /// When `hook` returns a [`HookTask`], the task is polled each frame by
+    /// [`poll_hook_tasks`] until it completes. The [`SceneHooked`] marker is
+    /// only added, and [`SceneHookCompleted`] only sent, once all the tasks
+    /// of the scene completed, so
+    /// [`HookedSceneState`](crate::HookedSceneState) doesn't consider the
+    /// scene loaded until then.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::task::Poll;
+    /// # use bevy::{ecs::system::EntityCommands, prelude::*};
+    /// use bevy_scene_hook::{HookTask, SceneHook};
+    /// # #[derive(Component)] struct Texture;
+    ///
+    /// struct LoadTexture(Handle<Image>);
+    /// impl HookTask for LoadTexture {
+    ///     fn poll(&mut self, entity: &mut EntityCommands, world: &World) -> Poll<()> {
+    ///         if world.resource::<Assets<Image>>().contains(&self.0) {
+    ///             entity.insert(Texture);
+    ///             Poll::Ready(())
+    ///         } else {
+    ///             Poll::Pending
+    ///         }
+    ///     }
+    /// }
+    /// let texture: Handle<Image> = Handle::default();
+    /// let hook = SceneHook::new_deferred(move |entity, _| {
+    ///     let is_screen = entity.get::<Name>().map(Name::as_str) == Some("Screen");
+    ///     is_screen.then(|| Box::new(LoadTexture(texture.clone())) as Box<dyn HookTask>)
+    /// });
+    /// ```
+    pub fn new_deferred<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands) -> Option<Box<dyn HookTask>> + Send + Sync + 'static,
+    {
+        Self::from_kind(HookKind::Deferred(Arc::new(
+            move |entity, cmds, _, root| {
+                let Some(task) = hook(entity, cmds) else {
+                    return ControlFlow::Continue(());
+                };
+                let hooked = cmds.id();
+                cmds.commands()
+                    .entity(root)
+                    .add(move |root, world: &mut World| {
+                        if let Some(mut tasks) = world.get_mut::<HookTasks>(root) {
+                            tasks.tasks.push((hooked, task));
+                        }
+                    });
+                ControlFlow::Continue(())
+            },
+        )))
+    }
+
+    /// Add a hook inserting the components of `table` on entities which
+    /// [`Name`] is the table key.
+    ///
+    /// The components are [`Reflect`] values, for example deserialized from
+    /// a RON file with [`UntypedReflectDeserializer`], so that designers can
+    /// describe what to insert on scene nodes without code. They are cloned
+    /// with [`Reflect::clone_value`] and inserted through their
+    /// [`ReflectComponent`].
+    ///
+    /// Components which type is not registered in `registry` with
+    /// `#[reflect(Component)]` are ignored, with a warning.
+    ///
+    /// [`UntypedReflectDeserializer`]: bevy::reflect::serde::UntypedReflectDeserializer
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::utils::HashMap;
+    /// use bevy_scene_hook::SceneHook;
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// let registry = AppTypeRegistry::default();
+    /// registry.write().register::<Health>();
+    ///
+    /// let mut table: HashMap<String, Vec<Box<dyn Reflect>>> = HashMap::new();
+    /// table.insert("Goblin".to_owned(), vec![Box::new(Health(10))]);
+    /// let hook = SceneHook::from_reflect_map(table, &registry);
+    /// ```
+    #[must_use]
+    pub fn from_reflect_map(
+        table: HashMap<String, Vec<Box<dyn Reflect>>>,
+        registry: &AppTypeRegistry,
+    ) -> Self {
+        let registry = registry.read();
+        let mut components = HashMap::with_capacity(table.len());
+        for (name, values) in table {
+            let mut reflected = Vec::with_capacity(values.len());
+            for value in values {
+                let type_id = value.get_represented_type_info().map(TypeInfo::type_id);
+                let reflect = type_id.and_then(|id| registry.get_type_data::<ReflectComponent>(id));
+                let Some(reflect) = reflect else {
+                    let type_path = value.reflect_type_path();
+                    bevy::log::warn!(
+                        "Can't insert `{type_path}` on '{name}': it doesn't `#[reflect(Component)]`"
+                    );
+                    continue;
+                };
+                reflected.push((reflect.clone(), value));
+            }
+            components.insert(name, reflected);
+        }
+        Self::new(move |entity, cmds| {
+            let name = entity.get::<Name>().map(Name::as_str);
+            let Some(reflected) = name.and_then(|name| components.get(name)) else {
+                return;
+            };
+            for (reflect, value) in reflected {
+                let reflect = reflect.clone();
+                let value = value.clone_value();
+                cmds.add(move |id, world: &mut World| {
+                    let registry = world.resource::<AppTypeRegistry>().clone();
+                    if let Some(mut entity) = world.get_entity_mut(id) {
+                        reflect.insert(&mut entity, &*value, &registry.read());
+                    }
+                });
+            }
+        })
+    }
+
+    /// Add a hook doing nothing, to only mark the scene as [`SceneHooked`].
+    ///
+    /// Use this for scenes that don't need any component, but should still be
+    /// tracked as hooked, for example by [`HookedSceneState`]. Contrary to a
+    /// hook with an empty closure, [`run_hooks`] doesn't visit the scene
+    /// entities, and sends no [`HookedEntity`] events. Settings such as
+    /// [`SceneHook::root`] and [`SceneHook::on_loaded`] still apply.
+    ///
+    /// You may also insert [`SceneHooked`] yourself on the scene entity,
+    /// hooks never run on scenes that already have it.
+    ///
+    /// [`HookedSceneState`]: crate::HookedSceneState
+    #[must_use]
+    pub fn noop() -> Self {
+        Self::from_kind(HookKind::Noop)
+    }
+
+    /// Add a hook running only on entities which [`Name`] is `name`.
+    ///
+    /// Use [`SceneHookBuilder`](crate::SceneHookBuilder) to run different
+    /// hooks on several names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Collider;
+    ///
+    /// let hook = SceneHook::named("Collider", |cmds| {
+    ///     cmds.insert(Collider);
+    /// });
+    /// ```
+    pub fn named<F>(name: impl Into<String>, hook: F) -> Self
+    where
+        F: Fn(&mut EntityCommands) + Send + Sync + 'static,
+    {
+        let name = name.into();
+        Self::new(move |entity, cmds| {
+            if entity.get::<Name>().map(Name::as_str) == Some(&name) {
+                hook(cmds);
+            }
+        })
+    }
+
+    /// Add a hook removing the `C` component from all scene entities with one.
+    ///
+    /// Use it to remove components added by the scene loader you don't need,
+    /// for example lights of a glTF file. Chain it with [`SceneHook::and_then`]
+    /// to also run your own hook.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Pile;
+    ///
+    /// let hook = SceneHook::strip::<PointLight>()
+    ///     .and_then(SceneHook::named("Pile", |cmds| { cmds.insert(Pile); }));
+    /// ```
+    #[must_use]
+    pub fn strip<C: Component>() -> Self {
+        Self::new(|entity, cmds| {
+            if entity.contains::<C>() {
+                cmds.remove::<C>();
+            }
+        })
+    }
+
+    /// Add a hook running only on entities which [`Name`] matches `pattern`.
+    ///
+    /// `pattern` is a simple glob, where `*` matches any text:
+    /// - `"Spawn*"` matches names starting with `Spawn`, such as `Spawn.001`.
+    /// - `"*.collider"` matches names ending with `.collider`.
+    /// - `"*Lamp*"` matches names containing `Lamp`.
+    /// - `"*"` matches all named entities.
+    /// - Without `*`, the name must be equal to `pattern`.
+    ///
+    /// `*` anywhere else than at the start or end of `pattern` is matched literally.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct SpawnPoint;
+    ///
+    /// // Blender names duplicated objects `Spawn.001`, `Spawn.002`…
+    /// let hook = SceneHook::new_matching("Spawn*", |_entity, cmds| {
+    ///     cmds.insert(SpawnPoint);
+    /// });
+    /// ```
+    pub fn new_matching<F>(pattern: &str, hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        let pattern = NamePattern::parse(pattern);
+        Self::new(move |entity, cmds| {
+            let name = entity.get::<Name>().map(Name::as_str);
+            if name.is_some_and(|name| pattern.matches(name)) {
+                hook(entity, cmds);
+            }
+        })
+    }
+
+    /// Add a hook running only on entities which [`Name`] matches a `N` node.
+    ///
+    /// See [`SceneNode`] for how to define nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{SceneHook, SceneNode};
+    /// # #[derive(Component)] struct Pile; #[derive(Component)] struct Card;
+    ///
+    /// #[derive(SceneNode)]
+    /// enum Node { Pile, Card, Table }
+    ///
+    /// let hook = SceneHook::match_node(|node: Node, cmds| {
+    ///     match node {
+    ///         Node::Pile => cmds.insert(Pile),
+    ///         Node::Card => cmds.insert(Card),
+    ///         Node::Table => cmds,
+    ///     };
+    /// });
+    /// ```
+    pub fn match_node<N, F>(hook: F) -> Self
+    where
+        N: SceneNode,
+        F: Fn(N, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        Self::new(move |entity, cmds| {
+            let name = entity.get::<Name>().map(Name::as_str);
+            if let Some(node) = name.and_then(N::from_name) {
+                hook(node, cmds);
+            }
+        })
+    }
+
+    /// Add a hook with mutable access to the `R` resource and read access to the `World`.
+    ///
+    /// This is useful to add assets to an `Assets<T>` based on the content of
+    /// the scene, for example to build colliders from meshes.
+    ///
+    /// Unlike [`SceneHook::new`], this is ran by the exclusive
+    /// [`run_exclusive_hooks`] system. The `R` resource is removed from the
+    /// `World` while the hook runs, using [`World::resource_scope`].
+    ///
+    /// # Panics
+    ///
+    /// When running the hook if the `R` resource doesn't exist.
+    pub fn new_with_resource<R, F>(hook: F) -> Self
+    where
+        R: Resource,
+        F: Fn(&EntityRef, &mut EntityCommands, &mut R, &World) + Send + Sync + 'static,
+    {
+        let exclusive = move |world: &mut World, _, entities: &[Entity]| {
+            let mut queue = CommandQueue::default();
+            world.resource_scope(|world, mut resource: Mut<R>| {
+                let mut cmds = Commands::new(&mut queue, world);
+                for entity_ref in entities.iter().filter_map(|e| world.get_entity(*e)) {
+                    let mut cmd = cmds.entity(entity_ref.id());
+                    hook(&entity_ref, &mut cmd, &mut resource, world);
+                }
+            });
+            queue.apply(world);
+        };
+        Self::from_kind(HookKind::Exclusive(Arc::new(exclusive)))
+    }
+
+    /// Add a hook running the one-shot `system` once per scene instance.
+    ///
+    /// The system takes a [`SceneEntities`] input, with the scene entity
+    /// and the other scene entities, and can use any other system parameter.
+    /// Register it with [`World::register_system`].
+    ///
+    /// The system is ran with [`World::run_system_with_input`] by the
+    /// [`run_exclusive_hooks`] system, so its commands are applied right
+    /// after it runs. If `system` is not registered, a warning is logged
+    /// and the scene is still marked as hooked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{SceneEntities, SceneHook};
+    ///
+    /// #[derive(Component)]
+    /// struct Card;
+    ///
+    /// fn hook_cards(In(scene): In<SceneEntities>, names: Query<&Name>, mut cmds: Commands) {
+    ///     for entity in scene.entities {
+    ///         if names.get(entity).is_ok_and(|name| name.as_str() == "Card") {
+    ///             cmds.entity(entity).insert(Card);
+    ///         }
+    ///     }
+    /// }
+    /// let mut world = World::new();
+    /// let hook = SceneHook::from_system(world.register_system(hook_cards));
+    /// ```
+    #[must_use]
+    pub fn from_system(system: SystemId<SceneEntities>) -> Self {
+        let exclusive = move |world: &mut World, root, entities: &[Entity]| {
+            let entities = entities.strip_suffix(&[root]).unwrap_or(entities);
+            let input = SceneEntities { root, entities: entities.to_vec() };
+            if let Err(err) = world.run_system_with_input(system, input) {
+                bevy::log::warn!("Couldn't run hook system of {root:?}: {err}");
+            }
+        };
+        Self::from_kind(HookKind::Exclusive(Arc::new(exclusive)))
+    }
+
+    /// Add a hook replacing the [`StandardMaterial`] of scene entities with
+    /// the material returned by `map`.
+    ///
+    /// `map` receives each material of the scene once, even if several
+    /// entities share it, and returns the material to use instead, which is
+    /// added to `Assets<StandardMaterial>`. Materials not loaded yet when
+    /// the scene is hooked are left as is.
+    ///
+    /// Like [`SceneHook::new_with_resource`], this is ran by [`run_exclusive_hooks`].
+    ///
+    /// # Panics
+    ///
+    /// When running the hook if there is no `Assets<StandardMaterial>` resource.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    ///
+    /// // Make everything shiny.
+    /// let hook = SceneHook::map_materials(|material| StandardMaterial {
+    ///     perceptual_roughness: 0.1,
+    ///     ..material.clone()
+    /// });
+    /// ```
+    #[cfg(feature = "pbr")]
+    pub fn map_materials<F>(map: F) -> Self
+    where
+        F: Fn(&StandardMaterial) -> StandardMaterial + Send + Sync + 'static,
+    {
+        type Materials = Assets<StandardMaterial>;
+        let exclusive = move |world: &mut World, _, entities: &[Entity]| {
+            world.resource_scope(|world, mut materials: Mut<Materials>| {
+                let mut mapped = HashMap::new();
+                for entity in entities {
+                    let Some(handle) = world.get::<Handle<StandardMaterial>>(*entity) else {
+                        continue;
+                    };
+                    let id = handle.id();
+                    let new_handle = if let Some(new_handle) = mapped.get(&id) {
+                        Handle::clone(new_handle)
+                    } else {
+                        let Some(material) = materials.get(id) else {
+                            continue;
+                        };
+                        let material = map(material);
+                        let new_handle = materials.add(material);
+                        mapped.insert(id, new_handle.clone());
+                        new_handle
+                    };
+                    world.entity_mut(*entity).insert(new_handle);
+                }
+            });
+        };
+        Self::from_kind(HookKind::Exclusive(Arc::new(exclusive)))
+    }
+
+    fn from_kind(hook: HookKind) -> Self {
+        Self {
+            hook,
+            track_inserted: false,
+            marker: true,
+            stable_order: false,
+            parallel: false,
+            rehookable: false,
+            shallow: false,
+            templated: false,
+            on_loaded: None,
+            root: None,
+            on_despawn: None,
+            world_finish: None,
+            reset: None,
+            root_name: None,
+            timeout: None,
+            fallback: None,
+            gate: None,
+            budget: None,
+        }
+    }
+
+    /// Run `other` after this hook on each entity, to combine hooks.
+    ///
+    /// This lets independent plugins each contribute a hook to the same scene.
+    /// Both hooks run on an entity before moving to the next entity. If
+    /// either hook returns [`ControlFlow::Break`], see [`SceneHook::new_flow`],
+    /// no hook runs on the following entities.
+    ///
+    /// When combining a hook created with [`SceneHook::new_with_resource`]
+    /// with another hook, both run in [`run_exclusive_hooks`]. Then the
+    /// [`HookTask`]s of [`SceneHook::new_deferred`] hooks are ignored.
+    ///
+    /// Only the settings of `self`, such as [`SceneHook::on_loaded`], are kept,
+    /// the settings of `other` are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Collider;
+    /// # #[derive(Component)] struct Pickup;
+    ///
+    /// let physics = SceneHook::named("Collider", |cmds| {
+    ///     cmds.insert(Collider);
+    /// });
+    /// let gameplay = SceneHook::named("Pickup", |cmds| {
+    ///     cmds.insert(Pickup);
+    /// });
+    /// let hook = physics.and_then(gameplay);
+    /// ```
+    #[must_use]
+    pub fn and_then(self, other: Self) -> Self {
+        use HookKind::{Commands, Deferred, Exclusive, Noop};
+        let hook = match (self.hook, other.hook) {
+            (Noop, hook) | (hook, Noop) => hook,
+            (Commands(first), Commands(then)) => Commands(chain_hooks(first, then)),
+            (Commands(first) | Deferred(first), Deferred(then))
+            | (Deferred(first), Commands(then)) => Deferred(chain_hooks(first, then)),
+            (Exclusive(first), Exclusive(then)) => {
+                Exclusive(Arc::new(move |world, root, entities| {
+                    first(world, root, entities);
+                    then(world, root, entities);
+                }))
+            }
+            (Exclusive(first), Commands(then) | Deferred(then)) => {
+                Exclusive(Arc::new(move |world, root, entities| {
+                    first(world, root, entities);
+                    run_hook_exclusive(&*then, world, root, entities);
+                }))
+            }
+            (Commands(first) | Deferred(first), Exclusive(then)) => {
+                Exclusive(Arc::new(move |world, root, entities| {
+                    run_hook_exclusive(&*first, world, root, entities);
+                    then(world, root, entities);
+                }))
+            }
+        };
+        let reset = chain_settings(self.reset, other.reset, |first, then| {
+            Arc::new(move |root| {
+                first(root);
+                then(root);
+            })
+        });
+        Self { hook, reset, ..self }
+    }
+
+    /// Combine this hook with the marker hook `other`, see [`MarkerHooks`].
+    ///
+    /// Like [`SceneHook::and_then`], but the settings of `other` are kept:
+    /// its callbacks, such as [`SceneHook::on_loaded`], run after the ones of
+    /// `self`, and its other settings apply when `self` doesn't set them.
+    /// When both set a setting differently, the one of `self` is kept and a
+    /// warning is logged.
+    fn merge(self, other: Self) -> Self {
+        let settings = Self {
+            hook: HookKind::Noop,
+            track_inserted: self.track_inserted || other.track_inserted,
+            marker: merge_flag("no_marker", self.marker, other.marker),
+            stable_order: self.stable_order || other.stable_order,
+            parallel: merge_flag("parallel", self.parallel, other.parallel),
+            rehookable: self.rehookable || other.rehookable,
+            shallow: merge_flag("shallow", self.shallow, other.shallow),
+            templated: merge_flag("templated", self.templated, other.templated),
+            on_loaded: chain_settings(
+                self.on_loaded.clone(),
+                other.on_loaded.clone(),
+                |first, then| {
+                    Arc::new(move |cmds: &mut EntityCommands, world: &World| {
+                        match first(cmds, world) {
+                            SceneVerdict::Keep => then(cmds, world),
+                            verdict @ SceneVerdict::Despawn(_) => verdict,
+                        }
+                    })
+                },
+            ),
+            root: chain_settings(self.root.clone(), other.root.clone(), |first, then| {
+                Arc::new(move |cmds: &mut EntityCommands, root| {
+                    first(cmds, root);
+                    then(cmds, root);
+                })
+            }),
+            on_despawn: chain_settings(
+                self.on_despawn.clone(),
+                other.on_despawn.clone(),
+                |first, then| {
+                    Arc::new(move |root, world: &mut World| {
+                        first(root, world);
+                        then(root, world);
+                    })
+                },
+            ),
+            world_finish: chain_settings(
+                self.world_finish.clone(),
+                other.world_finish.clone(),
+                |first, then| {
+                    Arc::new(move |world: &mut World, root| {
+                        first(world, root);
+                        then(world, root);
+                    })
+                },
+            ),
+            reset: None,
+            root_name: merge_setting(
+                "with_root_name",
+                self.root_name.clone(),
+                other.root_name.clone(),
+            ),
+            timeout: merge_setting("with_timeout", self.timeout, other.timeout),
+            fallback: merge_setting(
+                "with_fallback",
+                self.fallback.clone(),
+                other.fallback.clone(),
+            ),
+            gate: merge_setting("gate", self.gate, other.gate),
+            budget: merge_setting("budgeted", self.budget, other.budget),
+        };
+        let combined = self.and_then(other);
+        Self {
+            hook: combined.hook,
+            reset: combined.reset,
+            ..settings
+        }
+    }
+
+    /// Only run the hook on entities with a `C` component.
+    ///
+    /// For example, use `with_component::<Handle<Mesh>>()` to only run the
+    /// hook on meshes, ignoring empty nodes.
+    ///
+    /// Whether entities have a `C` component is checked against the `World`
+    /// before the hook ran, components inserted by the hook are not considered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Outline;
+    ///
+    /// let hook = SceneHook::new(|_, cmds| {
+    ///     cmds.insert(Outline);
+    /// })
+    /// .with_component::<Handle<Mesh>>();
+    /// ```
+    #[must_use]
+    pub fn with_component<C: Component>(mut self) -> Self {
+        self.hook = match self.hook {
+            HookKind::Commands(hook) => HookKind::Commands(filter_hook::<C>(hook)),
+            HookKind::Deferred(hook) => HookKind::Deferred(filter_hook::<C>(hook)),
+            HookKind::Exclusive(hook) => {
+                HookKind::Exclusive(Arc::new(move |world, root, entities| {
+                    let has_c = |e: &&Entity| world.get::<C>(**e).is_some();
+                    let entities: Vec<_> = entities.iter().filter(has_c).copied().collect();
+                    hook(world, root, &entities);
+                }))
+            }
+            HookKind::Noop => HookKind::Noop,
+        };
+        self
+    }
+
+    /// Record the components inserted by this hook in a [`HookInserted`] component.
+    ///
+    /// Use [`RemoveHookInsertedExt::remove_hook_inserted`] to later remove the
+    /// components added by the hook, while keeping the ones from the scene file.
+    #[must_use]
+    pub fn track_inserted(mut self) -> Self {
+        self.track_inserted = true;
+        self
+    }
+
+    /// Do not insert the [`SceneHooked`] marker once the scene is hooked.
+    ///
+    /// The hook still runs exactly once per scene instance, [`run_hooks`]
+    /// keeps track of hooked instances internally instead.
+    ///
+    /// Note that [`HookedSceneState`](crate::HookedSceneState) and
+    /// [`is_scene_hooked`](crate::is_scene_hooked) rely on `SceneHooked`,
+    /// so they never consider scenes with this hook as loaded.
+    #[must_use]
+    pub fn no_marker(mut self) -> Self {
+        self.marker = false;
+        self
+    }
+
+    /// Run the hook again when a [`RehookRequest`] is inserted on the scene entity.
+    ///
+    /// The hook runs again on the existing scene entities, the scene is **not**
+    #[cfg_attr(
+        feature = "reload",
+        doc = "spawned again. Use [`reload::Hook`](crate::reload::Hook) to re-spawn scenes."
+    )]
+    #[cfg_attr(
+        not(feature = "reload"),
+        doc = "spawned again. Use `reload::Hook`, with the `reload` feature, to re-spawn scenes."
+    )]
+    ///
+    /// Removing [`SceneHooked`] also runs the hook again, whether the hook is
+    /// `rehookable` or not. Neither work with [`SceneHook::no_marker`] hooks.
+    #[must_use]
+    pub fn rehookable(mut self) -> Self {
+        self.rehookable = true;
+        self
+    }
+
+    /// Only run the hook on the direct children of the scene entity, and on
+    /// the scene entity itself.
+    ///
+    /// Use this when only the top-level nodes of the scene matter, for example
+    /// named groups, to avoid running the hook on their many descendants.
+    /// With [`run_hook_on_instance`], only the children of its `root` are hooked.
+    ///
+    /// The other entities are still part of the scene: they count in [`HookStats`]
+    /// and [`SceneHookCompleted`], and toward the [`SceneHook::budgeted`] budget,
+    /// so that [`HookProgress`] still ends at [`HookTotalEntities`].
+    #[must_use]
+    pub fn shallow(mut self) -> Self {
+        self.shallow = true;
+        self
+    }
+
+    /// Run the hook on the scene entities in a stable order.
+    ///
+    /// By default, the hook runs on entities in the order bevy stores them,
+    /// which depends on entity allocation and may differ between runs. With
+    /// `stable_order`, entities are sorted by [`Name`], then by the names of
+    /// their ancestors, then by their index in their parent's [`Children`].
+    ///
+    /// "Stable" means that the order is reproducible across runs given the same
+    /// scene asset. This is useful if the hook spawns entities and you need
+    /// deterministic entity spawning, for example in networked games.
+    ///
+    /// This has a cost: the hierarchy of each entity is walked up to the root
+    /// and the entities are sorted before running the hook.
+    #[must_use]
+    pub fn stable_order(mut self) -> Self {
+        self.stable_order = true;
+        self
+    }
+
+    /// Give up waiting for the scene after `timeout`.
+    ///
+    /// If the scene is not ready `timeout` after the `SceneHook` was added,
+    /// [`time_out_hooks`] sends a [`SceneHookTimedOut`] event and inserts the
+    /// [`HookTimedOut`] marker on the scene entity. This is useful to report
+    /// scenes that failed to load, for example because of a wrong path.
+    ///
+    /// The hook still runs if the scene gets ready after timing out.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Spawn `fallback` instead of the scene if it fails to load.
+    ///
+    /// [`use_fallback_scenes`] replaces the `Handle<Scene>` of the scene entity
+    /// with `fallback` once the [`AssetServer`] reports the scene failed to load,
+    /// bevy then spawns `fallback`. The hook runs on the `fallback` entities,
+    /// so a placeholder scene can have nodes with the same names as the scene.
+    ///
+    /// This only works with [`HookedSceneBundle`](crate::HookedSceneBundle),
+    /// the fallback is not used for [`DynamicScene`](bevy::scene::DynamicScene)s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookedSceneBundle, SceneHook};
+    /// # #[derive(Component)] struct Pile;
+    ///
+    /// fn load_scene(mut cmds: Commands, assets: Res<AssetServer>) {
+    ///     let hook = SceneHook::named("Pile", |cmds| { cmds.insert(Pile); })
+    ///         .with_fallback(assets.load("placeholder.glb#Scene0"));
+    ///     cmds.spawn(HookedSceneBundle::new(assets.load("scene.glb#Scene0"), hook));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_fallback(mut self, fallback: Handle<Scene>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Only run the hook once the scene entity has a `C` component.
+    ///
+    /// Scenes without `C` are left unhooked, and hooked as soon as `C` is
+    /// inserted on them. Use it to control when hooks run, for example to
+    /// hook scenes only once the level they are part of is activated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    /// # #[derive(Component)] struct Pile;
+    /// #[derive(Component)]
+    /// struct NeedsHook;
+    ///
+    /// let hook = SceneHook::named("Pile", |cmds| { cmds.insert(Pile); }).gated_by::<NeedsHook>();
+    /// ```
+    #[must_use]
+    pub fn gated_by<C: Component>(mut self) -> Self {
+        self.gate = Some(TypeId::of::<C>());
+        self
+    }
+
+    /// Run the hook once per scene asset, and copy what it inserted to the
+    /// other instances of the same scene.
+    ///
+    /// When spawning many copies of the same scene, the hook runs on the first
+    /// one, and the components it inserted on the scene entities are recorded
+    /// in [`HookTemplates`]. On later instances of the same `Handle<Scene>`, the
+    /// recorded components are cloned onto the corresponding entities instead
+    /// of running the hook.
+    ///
+    /// # Limitations
+    ///
+    /// - The template is only recorded if all the inserted components are
+    ///   registered with `#[reflect(Component)]`. Otherwise, a warning is
+    ///   logged and the hook runs on each instance as usual.
+    /// - Only inserted components are recorded. Other effects of the hook,
+    ///   such as spawning entities or removing components, only happen on the
+    ///   first instance. Components are copied as is, so components holding an
+    ///   `Entity` would refer to the entities of the first instance.
+    /// - The hook doesn't run on the scene entity of later instances, use
+    ///   [`SceneHook::root`] for the scene entity, it runs on each instance.
+    /// - All templated hooks of a scene should be the same hook, since the
+    ///   template is shared by all its instances. Use [`HookTemplates::remove`]
+    ///   to record a new template, for example when the scene asset changed.
+    /// - Templates are only used with [`SceneHook::new`] and similar hooks
+    ///   ran by [`run_hooks`], and not with [`SceneHook::budgeted`] scenes
+    ///   hooked over several frames. No [`HookedEntity`] events are sent for
+    ///   instances using the template.
+    #[must_use]
+    pub fn templated(mut self) -> Self {
+        self.templated = true;
+        self
+    }
+
+    /// The scene asset of `root`, if its hook uses [`SceneHook::templated`].
+    fn template_scene(&self, world: &World, root: Entity) -> Option<AssetId<Scene>> {
+        let templated = self.templated && matches!(self.hook, HookKind::Commands(_));
+        let scene = world.get::<Handle<Scene>>(root);
+        scene.filter(|_| templated).map(Handle::id)
+    }
+
+    /// Whether the [`SceneHook::gated_by`] component is on `root`, if any.
+    fn is_open(&self, world: &World, root: Entity) -> bool {
+        let root = world.get_entity(root);
+        self.gate.map_or(true, |gate| {
+            root.is_some_and(|root| root.contains_type_id(gate))
+        })
+    }
+
+    /// Run the hook on at most `max_per_frame` scene entities each frame.
+    ///
+    /// Use it to avoid frame drops when hooking huge scenes. [`run_hooks`]
+    /// tracks which entities were hooked with the [`HookProgress`] component
+    /// on the scene entity, and inserts [`SceneHooked`] only once all of them
+    /// are. The [`SceneHook::root`] hook runs in the first frame, and the
+    /// [`SceneHook::on_loaded`] callback in the last one.
+    ///
+    /// This has no effect on [`SceneHook::new_deferred`] hooks and hooks ran
+    /// by [`run_exclusive_hooks`].
+    ///
+    /// # Panics
+    ///
+    /// If `max_per_frame` is 0.
+    #[must_use]
+    pub fn budgeted(mut self, max_per_frame: usize) -> Self {
+        assert!(
+            max_per_frame > 0,
+            "SceneHook::budgeted requires a non-zero budget"
+        );
+        self.budget = Some(max_per_frame);
+        self
+    }
+
+    /// Run the hook on the scene entities in parallel.
+    ///
+    /// This is useful when the hook does expensive work, such as computing
+    /// colliders from meshes, on scenes with many entities. The entities are
+    /// split in batches, hooked in parallel on the [`ComputeTaskPool`].
+    ///
+    /// The commands of the hook are still applied in the same order as
+    /// without `parallel`, but the hook doesn't run in order, so
+    /// [`ControlFlow::Break`] of [`SceneHook::new_flow`] hooks is ignored.
+    ///
+    /// This only applies to hooks ran by [`run_hooks`], it does nothing with
+    /// [`SceneHook::new_with_resource`].
+    #[must_use]
+    pub fn parallel(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    /// Run `on_loaded` on the scene entity once the hook ran on all scene entities.
+    ///
+    /// `on_loaded` decides whether to keep the scene. When it returns
+    /// [`SceneVerdict::Despawn`], the scene entity is despawned recursively
+    /// and a [`SceneHookRejected`] event is sent. This is useful to validate
+    /// user-provided scenes, such as mods.
+    ///
+    /// The `World` is the same as seen by the hook, without the effects of the
+    /// hook's commands.
+    #[must_use]
+    pub fn on_loaded<F>(mut self, on_loaded: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World) -> SceneVerdict + Send + Sync + 'static,
+    {
+        self.on_loaded = Some(Arc::new(on_loaded));
+        self
+    }
+
+    /// Run `world_finish` once with the scene entity, after the hook ran on
+    /// all scene entities, with exclusive `World` access.
+    ///
+    /// Hooks only get a shared `&World` and `EntityCommands`, so they can't
+    /// add assets, for example a `Handle<Mesh>` built from the scene meshes.
+    /// Read what you need in the hook, for example in components inserted on
+    /// the scene entities, then create the assets in `world_finish`.
+    ///
+    /// `world_finish` runs after the commands of the hook are applied, and
+    /// before [`SceneHook::on_loaded`]. It runs in the same frame as the
+    /// [`SceneHooked`] insertion, except for [`SceneHook::new_deferred`] hooks,
+    /// where it runs before their [`HookTask`]s complete. With
+    /// [`SceneHook::budgeted`], it runs once the last entity is hooked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookRoot, SceneHook};
+    ///
+    /// #[derive(Component)]
+    /// struct Tinted;
+    ///
+    /// let hook = SceneHook::named("Lamp", |cmds| { cmds.insert(Tinted); })
+    ///     .world_finish(|world, root| {
+    ///         let texture = world.resource_mut::<Assets<Image>>().add(Image::default());
+    ///         let mut tinted = world.query_filtered::<(Entity, &HookRoot), With<Tinted>>();
+    ///         let tinted: Vec<Entity> = tinted
+    ///             .iter(world)
+    ///             .filter_map(|(entity, scene)| (scene.0 == root).then_some(entity))
+    ///             .collect();
+    ///         for entity in tinted {
+    ///             world.entity_mut(entity).insert(texture.clone());
+    ///         }
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn world_finish<F>(mut self, world_finish: F) -> Self
+    where
+        F: Fn(&mut World, Entity) + Send + Sync + 'static,
+    {
+        self.world_finish = Some(Arc::new(world_finish));
+        self
+    }
+
+    /// Insert `name` as the [`Name`] of the scene entity when hooking it.
+    ///
+    /// The scene entity has no `Name` otherwise, this makes it easier to find
+    /// scenes spawned in bulk in an inspector or in logs. The name is inserted
+    /// before [`SceneHook::root`] runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookedSceneBundle, SceneHook};
+    ///
+    /// fn spawn_enemy(mut cmds: Commands, assets: Res<AssetServer>) {
+    ///     let hook = SceneHook::new(|_, _| {}).with_root_name("Enemy");
+    ///     cmds.spawn(HookedSceneBundle::new(assets.load("enemy.glb#Scene0"), hook));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_root_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.root_name = Some(Name::new(name));
+        self
+    }
+
+    /// Run `root` once on the scene entity, before the hook runs on any entity.
+    ///
+    /// Use this for things that should happen once per scene, such as adding
+    /// a marker or a rigid body to the scene entity, instead of matching
+    /// the name of the scene root node in the hook.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::SceneHook;
+    ///
+    /// #[derive(Component)]
+    /// struct Level;
+    ///
+    /// let hook = SceneHook::new(|_, _| {}).root(|cmds, _root| {
+    ///     cmds.insert((Level, Name::new("Level")));
+    /// });
+    /// ```
+    #[must_use]
+    pub fn root<F>(mut self, root: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, Entity) + Send + Sync + 'static,
+    {
+        self.root = Some(Arc::new(root));
+        self
+    }
+
+    /// Run `on_despawn` on each scene entity, when the scene is despawned with
+    /// [`DespawnHookedExt::despawn_hooked`].
+    ///
+    /// `on_despawn` runs on the entities the hook ran on, while they are
+    /// still in the `World`. Use it to release resources allocated by the hook.
+    ///
+    /// Bevy doesn't notify when an entity is despawned, so `on_despawn` does
+    /// **not** run when the scene is despawned by other means, such as
+    /// `despawn_recursive`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{DespawnHookedExt, SceneHook};
+    /// # #[derive(Component)] struct GpuBuffer;
+    ///
+    /// let hook = SceneHook::new(|_, cmds| {
+    ///     cmds.insert(GpuBuffer);
+    /// })
+    /// .with_despawn(|entity, world| {
+    ///     if world.get::<GpuBuffer>(entity).is_some() {
+    ///         // Release the buffer.
+    ///     }
+    /// });
+    ///
+    /// fn unload_level(mut cmds: Commands, level: Query<Entity, With<SceneHook>>) {
+    ///     for level in &level {
+    ///         cmds.entity(level).despawn_hooked();
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_despawn<F>(mut self, on_despawn: F) -> Self
+    where
+        F: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.on_despawn = Some(Arc::new(on_despawn));
+        self
+    }
+
+    /// Run `hook` on `entity_refs`, in parallel with [`SceneHook::parallel`].
+    fn hook_entities(
+        &self,
+        hook: &HookFn,
+        entity_refs: &[EntityRef],
+        cmds: &mut Commands,
+        world: &World,
+        root: Entity,
+    ) -> HookedEntities {
+        let mut visited = Vec::with_capacity(entity_refs.len());
+        if self.parallel {
+            let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+            let queues = entity_refs.par_splat_map(pool, None, |batch| {
+                let mut queue = CommandQueue::default();
+                let mut cmds = Commands::new(&mut queue, world);
+                for entity_ref in batch {
+                    // `Break` is ignored, see `SceneHook::parallel`.
+                    let _ = self.hook_entity(hook, entity_ref, &mut cmds, world, root);
+                }
+                queue
+            });
+            for mut queue in queues {
+                cmds.append(&mut queue);
+            }
+            visited.extend(entity_refs.iter().map(|e| HookedEntity::new(root, e)));
+            return HookedEntities { visited, broke: false };
+        }
+        for entity_ref in entity_refs {
+            visited.push(HookedEntity::new(root, entity_ref));
+            if self
+                .hook_entity(hook, entity_ref, cmds, world, root)
+                .is_break()
+            {
+                return HookedEntities { visited, broke: true };
+            }
+        }
+        HookedEntities { visited, broke: false }
+    }
+
+    /// Run `hook` on `entity_ref`, with the bookkeeping of [`run_hooks`].
+    fn hook_entity(
+        &self,
+        hook: &HookFn,
+        entity_ref: &EntityRef,
+        cmds: &mut Commands,
+        world: &World,
+        root: Entity,
+    ) -> ControlFlow<()> {
+        let _span = bevy::log::trace_span!("hook_entity", entity = ?entity_ref.id()).entered();
+        let mut cmd = cmds.entity(entity_ref.id());
+        let flow = hook(entity_ref, &mut cmd, world, root);
+        if self.track_inserted {
+            let before = entity_ref.archetype().components().collect();
+            let root_name = entity_ref.id() == root && self.root_name.is_some();
+            cmd.add(RecordInserted { before, root_name });
+        }
+        if entity_ref.id() != root {
+            cmd.insert(HookRoot(root));
+        }
+        flow
+    }
+
+    /// The entities of the scene `root`, in hook order, including `root`.
+    ///
+    /// This is all the entities of `instance`, also with [`SceneHook::shallow`],
+    /// see [`SceneHook::visits`] for the ones the hook runs on.
+    fn entities(
+        &self,
+        world: &World,
+        spawner: &SceneSpawner,
+        instance: InstanceId,
+        root: Entity,
+    ) -> Vec<Entity> {
+        let mut entities: Vec<_> = spawner.iter_instance_entities(instance).collect();
+        if self.stable_order {
+            entities.sort_by_cached_key(|entity| stable_key(world, *entity));
+        }
+        entities.push(root);
+        entities
+    }
+
+    /// Whether the hook runs on `entity` of the scene `root`, see [`SceneHook::shallow`].
+    fn visits(&self, world: &World, root: Entity, entity: Entity) -> bool {
+        !self.shallow
+            || entity == root
+            || world.get::<Parent>(entity).is_some_and(|p| p.get() == root)
+    }
+}
+
+/// Combine the `first` and `then` callbacks of two hooks, see [`SceneHook::and_then`].
+fn chain_settings<F: ?Sized>(
+    first: Option<Arc<F>>,
+    then: Option<Arc<F>>,
+    chain: impl FnOnce(Arc<F>, Arc<F>) -> Arc<F>,
+) -> Option<Arc<F>> {
+    match (first, then) {
+        (Some(first), Some(then)) => Some(chain(first, then)),
+        (first, then) => first.or(then),
+    }
+}
+
+/// The `first` setting if it is set, `then` otherwise, see [`SceneHook::merge`].
+fn merge_setting<T: PartialEq>(name: &str, first: Option<T>, then: Option<T>) -> Option<T> {
+    if matches!((&first, &then), (Some(first), Some(then)) if first != then) {
+        bevy::log::warn!(
+            "Combined marker hooks set `SceneHook::{name}` differently, using the first"
+        );
+    }
+    first.or(then)
+}
+
+/// The `first` flag, warning if `then` differs, see [`SceneHook::merge`].
+fn merge_flag(name: &str, first: bool, then: bool) -> bool {
+    if first != then {
+        bevy::log::warn!(
+            "Combined marker hooks set `SceneHook::{name}` differently, using the first"
+        );
+    }
+    first
+}
+
+/// The entities [`SceneHook::hook_entities`] ran on.
+#[derive(Default)]
+struct HookedEntities {
+    visited: Vec<HookedEntity>,
+    /// Whether the hook returned [`ControlFlow::Break`].
+    broke: bool,
+}
+
+/// Run `first` then `then` on each entity, see [`SceneHook::and_then`].
+fn chain_hooks(first: Arc<HookFn>, then: Arc<HookFn>) -> Arc<HookFn> {
+    Arc::new(move |entity, cmds, world, root| {
+        let first = first(entity, cmds, world, root);
+        let then = then(entity, cmds, world, root);
+        if first.is_break() || then.is_break() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Only run `hook` on entities with a `C` component, see [`SceneHook::with_component`].
+fn filter_hook<C: Component>(hook: Arc<HookFn>) -> Arc<HookFn> {
+    Arc::new(move |entity, cmds, world, root| {
+        if entity.contains::<C>() {
+            hook(entity, cmds, world, root)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Run a [`HookKind::Commands`] hook on `entities`, from an exclusive hook.
+fn run_hook_exclusive(hook: &HookFn, world: &mut World, root: Entity, entities: &[Entity]) {
+    let mut queue = CommandQueue::default();
+    let mut cmds = Commands::new(&mut queue, world);
+    for entity_ref in entities.iter().filter_map(|e| world.get_entity(*e)) {
+        let mut cmd = cmds.entity(entity_ref.id());
+        if hook(&entity_ref, &mut cmd, world, root).is_break() {
+            break;
+        }
+    }
+    queue.apply(world);
+}
+
+/// A [`SceneHook::new_matching`] pattern.
+enum NamePattern {
+    Exact(String),
+    Prefix(String),
+    Suffix(String),
+    Contains(String),
+}
+impl NamePattern {
+    fn parse(pattern: &str) -> Self {
+        let prefix = pattern.strip_suffix('*');
+        let suffix = pattern.strip_prefix('*');
+        match (prefix, suffix) {
+            (Some(_), Some(_)) if pattern.len() <= 2 => Self::Contains(String::new()),
+            (Some(_), Some(_)) => Self::Contains(pattern[1..pattern.len() - 1].to_owned()),
+            (Some(prefix), None) => Self::Prefix(prefix.to_owned()),
+            (None, Some(suffix)) => Self::Suffix(suffix.to_owned()),
+            (None, None) => Self::Exact(pattern.to_owned()),
+        }
+    }
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Exact(exact) => name == exact,
+            Self::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            Self::Suffix(suffix) => name.ends_with(suffix.as_str()),
+            Self::Contains(infix) => name.contains(infix.as_str()),
+        }
+    }
+}
+
+/// Run [`SceneHook::on_loaded`] on the scene `root`, despawning it if rejected.
+fn judge(on_loaded: &OnLoadedFn, cmds: &mut Commands, world: &World, root: Entity) {
+    let SceneVerdict::Despawn(reason) = on_loaded(&mut cmds.entity(root), world) else {
+        return;
+    };
+    cmds.entity(root).despawn_recursive();
+    cmds.add(move |world: &mut World| {
+        world.send_event(SceneHookRejected { root, reason });
+    });
+}
+
+/// The sort key used by [`SceneHook::stable_order`].
+///
+/// It is the entity's name, then the names and sibling indices of its ancestors.
+fn stable_key(world: &World, entity: Entity) -> (Option<&str>, Vec<Option<&str>>, Vec<usize>) {
+    let name = |entity| world.get::<Name>(entity).map(Name::as_str);
+    let mut names = Vec::new();
+    let mut indices = Vec::new();
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        let siblings = world.get::<Children>(parent.get());
+        let index = siblings.and_then(|s| s.iter().position(|e| *e == current));
+        names.push(name(current));
+        indices.push(index.unwrap_or(usize::MAX));
+        current = parent.get();
+    }
+    names.reverse();
+    indices.reverse();
+    (name(entity), names, indices)
+}
+
+/// Scenes which hook didn't run yet.
+type Unhooked = (Without<SceneHooked>, Without<HookTasks>);
+
+/// Run once [`SceneHook`]s added to [`SceneBundle`](crate::SceneBundle) or
+/// [`DynamicSceneBundle`](crate::DynamicSceneBundle) when the scenes are loaded.
+///
+/// The hooks only run once the scene instance is ready, that is, once all its
+/// entities are spawned.
+///
+/// Instances hooked with a [`SceneHook::no_marker`] hook are tracked in
+/// a system-local set rather than with the [`SceneHooked`] marker.
+///
+/// The commands of a scene's hook are dropped if the scene entity is despawned
+/// before they are applied, for example by another system's commands.
+pub fn run_hooks(
+    unloaded_instances: Query<(Entity, &SceneInstance, &SceneHook), Unhooked>,
+    scene_manager: Res<SceneSpawner>,
+    world: &World,
+    mut commands: Commands,
+    mut unmarked: Local<HashSet<InstanceId>>,
+) {
+    unmarked.retain(|instance| scene_manager.instance_is_ready(*instance));
+    for (entity, instance, hooked) in unloaded_instances.iter() {
+        if matches!(hooked.hook, HookKind::Exclusive(_)) {
+            continue;
+        }
+        if unmarked.contains(&**instance) || !scene_manager.instance_is_ready(**instance) {
+            continue;
+        }
+        if !hooked.is_open(world, entity) {
+            continue;
+        }
+        let mut queue = CommandQueue::default();
+        let mut cmds = Commands::new(&mut queue, world);
+        let done = hook_instance(&mut cmds, world, &scene_manager, **instance, entity, hooked);
+        if done && !hooked.marker {
+            unmarked.insert(**instance);
+        }
+        commands.add(move |world: &mut World| {
+            if world.get_entity(entity).is_some() {
+                queue.apply(world);
+            }
+        });
+    }
+}
+
+/// Run a non-exclusive `hooked` on the next entities of `instance`, resuming
+/// from the [`HookProgress`] of `root`.
+///
+/// Returns whether the hook is done with the scene.
+fn hook_instance(
+    cmds: &mut Commands,
+    world: &World,
+    scene_manager: &SceneSpawner,
+    instance: InstanceId,
+    root: Entity,
+    hooked: &SceneHook,
+) -> bool {
+    let hook = match &hooked.hook {
+        HookKind::Commands(hook) | HookKind::Deferred(hook) => Some(hook),
+        HookKind::Noop | HookKind::Exclusive(_) => None,
+    };
+    let deferred = matches!(hooked.hook, HookKind::Deferred(_));
+    let entities = hooked.entities(world, scene_manager, instance, root);
+    let count = entities.len() - 1;
+    let _span = bevy::log::info_span!("run_hooks", root = ?root, entities = count).entered();
+    let start = world
+        .get::<HookProgress>(root)
+        .map_or(0, |progress| progress.next);
+    let end = match hooked.budget {
+        Some(budget) if !deferred => entities.len().min(start + budget),
+        _ => entities.len(),
+    };
+    // A cursor inserted on a scene not hooked yet still sets the scene up.
+    if start == 0 || world.get::<HookStats>(root).is_none() {
+        start_hook(cmds, world, instance, root, hooked, &entities);
+    }
+    let template = hooked
+        .template_scene(world, root)
+        .filter(|_| start == 0 && end == entities.len());
+    let use_template = template.is_some_and(|scene| HookTemplates::can_apply(world, scene, count));
+    // `Noop` hooks and instances using a template don't visit the scene entities.
+    let hook = hook.filter(|_| !use_template);
+    let to_hook = if hook.is_some() { &entities[start.min(end)..end] } else { &[] };
+    let entity_refs: Vec<_> = to_hook
+        .iter()
+        .filter(|e| hooked.visits(world, root, **e))
+        .filter_map(|e| world.get_entity(*e))
+        .collect();
+    let HookedEntities { visited, broke } = hook.map_or_else(HookedEntities::default, |hook| {
+        hooked.hook_entities(&**hook, &entity_refs, cmds, world, root)
+    });
+    if let Some(scene) = template {
+        add_template_command(scene, use_template, root, &entities[..count], cmds, world);
+    }
+    cmds.add(move |world: &mut World| {
+        world.send_event_batch(visited);
+    });
+    let done = hook.is_none() || broke || end == entities.len();
+    if done {
+        let hooked = hooked.clone();
+        cmds.add(move |world: &mut World| finish_hook(world, root, &hooked, &entities));
+    } else {
+        cmds.entity(root).insert(HookProgress { next: end });
+    }
+    done
+}
+
+/// Set up the scene `root` before its hook visits the scene `entities`.
+///
+/// This inserts [`HookStats`] and [`HookInstance`], and runs the
+/// [`SceneHook::root`] hook. All hook runners share it.
+fn start_hook(
+    cmds: &mut Commands,
+    world: &World,
+    instance: InstanceId,
+    root: Entity,
+    hooked: &SceneHook,
+    entities: &[Entity],
+) {
+    let stats = HookStats::count(world, root, entities);
+    let mut root_cmds = cmds.entity(root);
+    root_cmds.insert((stats, HookInstance(instance)));
+    if matches!(hooked.hook, HookKind::Deferred(_)) {
+        root_cmds.insert(HookTasks::new(entities.len() - 1));
+    }
+    if let Some(name) = &hooked.root_name {
+        root_cmds.insert(name.clone());
+    }
+    if let Some(root_hook) = &hooked.root {
+        root_hook(&mut root_cmds, root);
+    }
+}
+
+/// Complete the hook of the scene `root`, once it visited the scene `entities`.
+///
+/// This runs [`SceneHook::world_finish`] and [`SceneHook::on_loaded`]. Unless
+/// `on_loaded` despawned the scene, it is then marked [`SceneHooked`] and
+/// [`SceneHookCompleted`] is sent. Deferred hooks are marked and completed
+/// once their tasks are, see [`poll_hook_tasks`]. All hook runners share it.
+fn finish_hook(world: &mut World, root: Entity, hooked: &SceneHook, entities: &[Entity]) {
+    if let Some(reset) = &hooked.reset {
+        reset(root);
+    }
+    if let Some(world_finish) = &hooked.world_finish {
+        world_finish(world, root);
+    }
+    if let Some(on_loaded) = &hooked.on_loaded {
+        let mut queue = CommandQueue::default();
+        judge(
+            &**on_loaded,
+            &mut Commands::new(&mut queue, world),
+            world,
+            root,
+        );
+        queue.apply(world);
+    }
+    let Some(mut root_entity) = world.get_entity_mut(root) else {
+        return;
+    };
+    root_entity.remove::<HookProgress>();
+    if matches!(hooked.hook, HookKind::Deferred(_)) {
+        return;
+    }
+    if hooked.marker {
+        root_entity.insert(SceneHooked);
+    }
+    world.send_event(SceneHookCompleted { root, entity_count: entities.len() - 1 });
+}
+
+/// Apply the template of `scene` to the `entities` of `root`, or record one if there is none.
+fn add_template_command(
+    scene: AssetId<Scene>,
+    use_template: bool,
+    root: Entity,
+    entities: &[Entity],
+    cmds: &mut Commands,
+    world: &World,
+) {
+    let entities = entities.to_vec();
+    if use_template {
+        cmds.add(ApplyTemplate { scene, root, entities });
+    } else if HookTemplates::must_record(world, scene) {
+        let components = |e: &Entity| {
+            let entity = world.get_entity(*e);
+            entity.map_or_else(Vec::new, |e| e.archetype().components().collect())
+        };
+        let before = entities.iter().map(components).collect();
+        cmds.add(RecordTemplate { scene, entities, before });
+    }
+}
+
+/// Poll the [`HookTask`]s of [`SceneHook::new_deferred`] hooks.
+///
+/// Once all the tasks of a scene completed, the scene is marked as [`SceneHooked`]
+/// and [`SceneHookCompleted`] is sent.
+pub fn poll_hook_tasks(world: &mut World, pending: &mut QueryState<Entity, With<HookTasks>>) {
+    let roots: Vec<_> = pending.iter(world).collect();
+    for root in roots {
+        let Some(mut tasks) = world.get_mut::<HookTasks>(root) else {
+            continue;
+        };
+        let entity_count = tasks.entity_count;
+        let mut tasks = std::mem::take(&mut tasks.tasks);
+        let mut queue = CommandQueue::default();
+        let mut cmds = Commands::new(&mut queue, world);
+        tasks.retain_mut(|(entity, task)| {
+            let Some(mut entity) = cmds.get_entity(*entity) else {
+                return false;
+            };
+            task.poll(&mut entity, world).is_pending()
+        });
+        queue.apply(world);
+        let Some(mut root_entity) = world.get_entity_mut(root) else {
+            continue;
+        };
+        if !tasks.is_empty() {
+            root_entity.insert(HookTasks { tasks, entity_count });
+            continue;
+        }
+        let marker = root_entity
+            .get::<SceneHook>()
+            .map_or(true, |hook| hook.marker);
+        root_entity.remove::<HookTasks>();
+        if marker {
+            root_entity.insert(SceneHooked);
+        }
+        world.send_event(SceneHookCompleted { root, entity_count });
+    }
+}
+
+/// Run once [`SceneHook`]s created with [`SceneHook::new_with_resource`],
+/// when their scenes are loaded.
+///
+/// This is the exclusive system counterpart of [`run_hooks`].
+/// It logs a warning, once, instead of panicking if there is no [`SceneSpawner`].
+pub fn run_exclusive_hooks(
+    world: &mut World,
+    unloaded_instances: &mut QueryState<(Entity, &SceneInstance, &SceneHook), Without<SceneHooked>>,
+    mut unmarked: Local<HashSet<InstanceId>>,
+    mut warned: Local<bool>,
+) {
+    let Some(scene_manager) = world.get_resource::<SceneSpawner>() else {
+        if !*warned {
+            *warned = true;
+            bevy::log::warn!("No SceneSpawner resource, can't run exclusive scene hooks");
+        }
+        return;
+    };
+    unmarked.retain(|instance| scene_manager.instance_is_ready(*instance));
+
+    let mut to_run = Vec::new();
+    for (root, instance, hooked) in unloaded_instances.iter(world) {
+        if !matches!(hooked.hook, HookKind::Exclusive(_)) {
+            continue;
+        }
+        if unmarked.contains(&**instance) || !scene_manager.instance_is_ready(**instance) {
+            continue;
+        }
+        if !hooked.is_open(world, root) {
+            continue;
+        }
+        to_run.push((root, **instance, hooked.clone()));
+    }
+    for (root, instance, hooked) in to_run {
+        hook_instance_exclusive(world, instance, root, &hooked);
+        if !hooked.marker {
+            unmarked.insert(instance);
+        }
+    }
+}
+
+/// Run an exclusive `hooked` on all the entities of `instance`.
+fn hook_instance_exclusive(
+    world: &mut World,
+    instance: InstanceId,
+    root: Entity,
+    hooked: &SceneHook,
+) {
+    let HookKind::Exclusive(hook) = &hooked.hook else {
+        return;
+    };
+    let Some(scene_manager) = world.get_resource::<SceneSpawner>() else {
+        return;
+    };
+    let entities = hooked.entities(world, scene_manager, instance, root);
+    let count = entities.len() - 1;
+    let _span =
+        bevy::log::info_span!("run_exclusive_hooks", root = ?root, entities = count).entered();
+    let components = |e: &Entity| {
+        world
+            .get_entity(*e)
+            .map(|e| e.archetype().components().collect())
+    };
+    let to_hook: Vec<_> = entities
+        .iter()
+        .copied()
+        .filter(|e| hooked.visits(world, root, *e))
+        .collect();
+    let before: Vec<Option<Vec<_>>> = if hooked.track_inserted {
+        to_hook.iter().map(components).collect()
+    } else {
+        Vec::new()
+    };
+
+    let entity_refs = to_hook.iter().filter_map(|e| world.get_entity(*e));
+    let visited: Vec<_> = entity_refs.map(|e| HookedEntity::new(root, &e)).collect();
+    world.send_event_batch(visited);
+
+    let mut queue = CommandQueue::default();
+    start_hook(
+        &mut Commands::new(&mut queue, world),
+        world,
+        instance,
+        root,
+        hooked,
+        &entities,
+    );
+    queue.apply(world);
+    hook(world, root, &to_hook);
+
+    for (entity, before) in to_hook.iter().zip(before) {
+        if let Some(before) = before {
+            let root_name = *entity == root && hooked.root_name.is_some();
+            RecordInserted { before, root_name }.apply(*entity, world);
+        }
+    }
+    for entity in to_hook.iter().filter(|e| **e != root) {
+        if let Some(mut entity) = world.get_entity_mut(*entity) {
+            entity.insert(HookRoot(root));
+        }
+    }
+    finish_hook(world, root, hooked, &entities);
+}
+
+/// Run `hook` on the entities of the scene `instance`, right now.
+///
+/// [`run_hooks`] only hooks scenes spawned with a [`SceneInstance`] component,
+/// use this to hook scenes you spawned yourself, for example a
+/// [`DynamicScene`](bevy::scene::DynamicScene) written into an existing
+/// entity tree with [`SceneSpawner::spawn_dynamic_sync`].
+///
+/// `root` plays the role of the scene entity: the hook also runs on it,
+/// and it is the [`HookRoot`] of the instance entities. Use the entity the
+/// scene was written into, or any entity you want to track the scene with.
+///
+/// Returns `false` without running `hook` if `instance` is not ready, or if
+/// there is no [`SceneSpawner`], logging a warning. Like [`run_hooks`], this inserts [`SceneHooked`] on `root` and calls the
+/// [`SceneHook::on_loaded`] callback once the hook is done. A
+/// [`SceneHook::budgeted`] hook only visits the next entities of the scene on
+/// each call, resuming from the [`HookProgress`] of `root`: call this again
+/// until `root` has no `HookProgress`.
+///
+/// See the `in_place_dynamic_scene` example for the full workflow.
+pub fn run_hook_on_instance(
+    instance: InstanceId,
+    root: Entity,
+    hook: &SceneHook,
+    world: &mut World,
+) -> bool {
+    hook_instance_now(instance, root, hook, world).is_some()
+}
+
+/// Run `hooked` on `instance`, returning whether the hook is done with the
+/// scene, or `None` if `instance` is not ready.
+fn hook_instance_now(
+    instance: InstanceId,
+    root: Entity,
+    hooked: &SceneHook,
+    world: &mut World,
+) -> Option<bool> {
+    let Some(scene_manager) = world.get_resource::<SceneSpawner>() else {
+        bevy::log::warn!("No SceneSpawner resource, can't hook scene instance {instance:?}");
+        return None;
+    };
+    if !scene_manager.instance_is_ready(instance) {
+        return None;
+    }
+    if matches!(hooked.hook, HookKind::Exclusive(_)) {
+        hook_instance_exclusive(world, instance, root, hooked);
+        return Some(true);
+    }
+    let mut queue = CommandQueue::default();
+    let mut cmds = Commands::new(&mut queue, world);
+    let done = hook_instance(&mut cmds, world, scene_manager, instance, root, hooked);
+    queue.apply(world);
+    Some(done)
+}
+
+/// The hooks ran on all scenes with a given marker component.
+///
+/// See [`HookAppExt::add_scene_hook`](crate::HookAppExt::add_scene_hook).
+#[derive(Resource, Default)]
+pub struct MarkerHooks(HashMap<TypeId, SceneHook>);
+impl MarkerHooks {
+    /// Run `hook` on scenes with the `C` component, after the hooks already
+    /// added for `C`, see [`SceneHook::and_then`].
+    ///
+    /// Unlike with `and_then`, the settings of `hook`, such as
+    /// [`SceneHook::on_loaded`], are kept. Its callbacks run after the ones
+    /// of the existing hooks, and a warning is logged if they configure the
+    /// same setting differently, the first hook wins.
+    pub fn insert<C: Component>(&mut self, hook: SceneHook) {
+        let hook = match self.0.remove(&TypeId::of::<C>()) {
+            Some(existing) => existing.merge(hook),
+            None => hook,
+        };
+        self.0.insert(TypeId::of::<C>(), hook);
+    }
+
+    /// Whether there is a hook for scenes with the `C` component.
+    #[must_use]
+    pub fn contains<C: Component>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<C>())
+    }
+}
+
+/// Insert the [`MarkerHooks`] hook of `C` on entities the `C` component is added to.
+///
+/// The hook is inserted as a [`SceneHook`] component, so that [`run_hooks`]
+/// and [`run_exclusive_hooks`] run it like any other `SceneHook`. If the
+/// entity already has a `SceneHook`, the marker hook runs after it, and the
+/// settings of both are combined as in [`MarkerHooks::insert`].
+pub fn run_marker_hooks<C: Component>(
+    marked: Query<(Entity, Option<&SceneHook>), Added<C>>,
+    hooks: Res<MarkerHooks>,
+    mut cmds: Commands,
+) {
+    let Some(hook) = hooks.0.get(&TypeId::of::<C>()) else {
+        return;
+    };
+    for (entity, existing) in &marked {
+        let hook = existing.map_or_else(
+            || hook.clone(),
+            |existing| existing.clone().merge(hook.clone()),
+        );
+        cmds.entity(entity).insert(hook);
+    }
+}
+
+/// Scenes tracked with [`HookInstanceExt::hook_instance`](crate::HookInstanceExt::hook_instance)
+/// which hook didn't run yet.
+type UnhookedInstance = (
+    Without<SceneInstance>,
+    Without<SceneHooked>,
+    Without<HookTasks>,
+);
+
+/// Run once [`SceneHook`]s added with
+/// [`HookInstanceExt::hook_instance`](crate::HookInstanceExt::hook_instance),
+/// when their scene instance is ready.
+///
+/// The hooks are ran like with [`run_hook_on_instance`].
+pub fn run_instance_hooks(
+    world: &mut World,
+    unloaded_instances: &mut QueryState<(Entity, &HookInstance, &SceneHook), UnhookedInstance>,
+    instances: &mut QueryState<&HookInstance>,
+    mut unmarked: Local<HashSet<InstanceId>>,
+) {
+    let live: HashSet<_> = instances.iter(world).map(|instance| instance.0).collect();
+    unmarked.retain(|instance| live.contains(instance));
+    let Some(scene_manager) = world.get_resource::<SceneSpawner>() else {
+        return;
+    };
+    let ready: Vec<_> = unloaded_instances
+        .iter(world)
+        .filter(|(root, _, hooked)| hooked.is_open(world, *root))
+        .filter(|(_, instance, _)| !unmarked.contains(&instance.0))
+        .filter(|(_, instance, _)| scene_manager.instance_is_ready(instance.0))
+        .map(|(root, instance, hooked)| (root, instance.0, hooked.clone()))
+        .collect();
+    for (root, instance, hooked) in ready {
+        let done = hook_instance_now(instance, root, &hooked, world);
+        if done == Some(true) && !hooked.marker {
+            unmarked.insert(instance);
+        }
+    }
+}
+
+/// Insert [`InstanceReady`] and [`HookTotalEntities`] on scene entities once
+/// their [`SceneInstance`] is ready.
+///
+/// [`HookPlugin`](crate::HookPlugin) runs it before the hook systems, so the
+/// component is added in the same frame as [`SceneHooked`] for scenes with a hook.
+/// It is inserted once, and not removed if the scene changes afterward.
+pub fn mark_ready_instances(
+    instances: Query<(Entity, &SceneInstance), Without<InstanceReady>>,
+    scene_manager: Res<SceneSpawner>,
+    mut cmds: Commands,
+) {
+    for (entity, instance) in &instances {
+        if scene_manager.instance_is_ready(**instance) {
+            let total = scene_manager.iter_instance_entities(**instance).count();
+            cmds.entity(entity)
+                .try_insert((InstanceReady, HookTotalEntities(total)));
+        }
+    }
+}
+
+/// Replace scenes that failed to load with their [`SceneHook::with_fallback`] scene.
+pub fn use_fallback_scenes(
+    mut scenes: Query<(&SceneHook, &mut Handle<Scene>), Without<SceneHooked>>,
+    assets: Res<AssetServer>,
+) {
+    for (hooked, mut handle) in &mut scenes {
+        let Some(fallback) = &hooked.fallback else {
+            continue;
+        };
+        if *handle == *fallback || assets.get_load_state(&*handle) != Some(LoadState::Failed) {
+            continue;
+        }
+        bevy::log::warn!("Scene {handle:?} failed to load, spawning its fallback {fallback:?}");
+        *handle = fallback.clone();
+    }
+}
+
+/// Scenes waiting to be hooked, see [`time_out_hooks`].
+type Waiting = (Without<SceneHooked>, Without<HookTimedOut>);
+
+/// The instance a waiting scene is hooked on, see [`time_out_hooks`].
+type WaitingInstance = (
+    Option<&'static SceneInstance>,
+    Option<&'static HookInstance>,
+);
+
+/// Send [`SceneHookTimedOut`] for scenes not ready before their [`SceneHook::with_timeout`].
+///
+/// The timeout starts the first time this system sees the `SceneHook`.
+/// Scenes hooked through [`HookInstance`] are ready once that instance is.
+pub fn time_out_hooks(
+    waiting: Query<(Entity, &SceneHook, WaitingInstance), Waiting>,
+    scene_manager: Res<SceneSpawner>,
+    time: Res<Time>,
+    mut cmds: Commands,
+    mut started: Local<HashMap<Entity, Duration>>,
+) {
+    let now = time.elapsed();
+    started.retain(|entity, _| waiting.contains(*entity));
+    for (entity, hooked, (instance, hook_instance)) in &waiting {
+        let Some(timeout) = hooked.timeout else {
+            continue;
+        };
+        let instance = hook_instance.map(|i| i.0).or_else(|| instance.map(|i| **i));
+        if instance.is_some_and(|instance| scene_manager.instance_is_ready(instance)) {
+            started.remove(&entity);
+            continue;
+        }
+        let start = *started.entry(entity).or_insert(now);
+        if now.saturating_sub(start) < timeout {
+            continue;
+        }
+        started.remove(&entity);
+        cmds.entity(entity).try_insert(HookTimedOut);
+        cmds.add(move |world: &mut World| {
+            world.send_event(SceneHookTimedOut { root: entity });
+        });
+    }
+}
+
+/// The scene instance and asset of a scene entity, read by the diagnostics systems.
+type CompletedScene = (
+    Option<&'static HookInstance>,
+    Option<&'static SceneInstance>,
+    Option<&'static Handle<Scene>>,
+);
+
+/// The instance and asset path of the scenes which hook completed, see [`SceneHookCompleted`].
+///
+/// This covers all hooked scenes, including [`SceneHook::no_marker`] ones and
+/// the ones hooked through [`HookInstance`].
+fn completed_scenes<'a>(
+    completed: &'a mut EventReader<SceneHookCompleted>,
+    scenes: &'a Query<CompletedScene>,
+    assets: &'a AssetServer,
+) -> impl Iterator<Item = (InstanceId, String)> + 'a {
+    completed.read().filter_map(|completed| {
+        let (hook_instance, instance, handle) = scenes.get(completed.root).ok()?;
+        let instance = hook_instance.map_or_else(|| instance.map(|i| **i), |i| Some(i.0))?;
+        let path = handle.and_then(|h| assets.get_path(h));
+        let path = path.map_or_else(|| "<no path>".to_owned(), |p| p.to_string());
+        Some((instance, path))
+    })
+}
+
+/// Warn about newly hooked scenes where no entity has a [`Name`] component.
+///
+/// Added by [`HookPlugin::warn_unnamed_scenes`](crate::HookPlugin::warn_unnamed_scenes).
+/// Newly hooked scenes are the ones in [`SceneHookCompleted`] events.
+pub fn warn_unnamed_scenes(
+    mut completed: EventReader<SceneHookCompleted>,
+    scenes: Query<CompletedScene>,
+    names: Query<(), With<Name>>,
+    scene_manager: Res<SceneSpawner>,
+    assets: Res<AssetServer>,
+) {
+    for (instance, path) in completed_scenes(&mut completed, &scenes, &assets) {
+        let mut entities = scene_manager.iter_instance_entities(instance).peekable();
+        if entities.peek().is_none() || entities.any(|e| names.contains(e)) {
+            continue;
+        }
+        bevy::log::warn!(
+            "Scene `{path}` was hooked, but none of its entities have a `Name` component. \
+            Name-based hooks will not match anything, \
+            check that the asset was exported and imported with node names."
+        );
+    }
+}
+
+/// Log at `debug` level the [`Name`]s of the entities of newly hooked scenes.
+///
+/// Added by [`HookPlugin::with_diagnostics`](crate::HookPlugin::with_diagnostics).
+/// Newly hooked scenes are the ones in [`SceneHookCompleted`] events.
+pub fn log_scene_names(
+    mut completed: EventReader<SceneHookCompleted>,
+    scenes: Query<CompletedScene>,
+    names: Query<&Name>,
+    scene_manager: Res<SceneSpawner>,
+    assets: Res<AssetServer>,
+) {
+    for (instance, path) in completed_scenes(&mut completed, &scenes, &assets) {
+        let entities = scene_manager.iter_instance_entities(instance);
+        let mut scene_names: Vec<_> = entities
+            .filter_map(|e| names.get(e).ok())
+            .map(Name::as_str)
+            .collect();
+        scene_names.sort_unstable();
+        scene_names.dedup();
+        bevy::log::debug!("Scene `{path}` was hooked, its entity names are: {scene_names:?}");
+    }
+}
+
+/// Remove [`SceneHooked`] from scenes with a [`RehookRequest`], so that
+/// [`run_hooks`] runs their [`SceneHook::rehookable`] hook again.
+pub fn rehook_requested_scenes(
+    requests: Query<(Entity, &SceneHook), With<RehookRequest>>,
+    mut cmds: Commands,
+) {
+    for (entity, hooked) in &requests {
+        let mut entity_cmds = cmds.entity(entity);
+        entity_cmds.remove::<RehookRequest>();
+        if hooked.rehookable {
+            entity_cmds.remove::<SceneHooked>();
+        } else {
+            bevy::log::warn!(
+                "RehookRequest on {entity:?} ignored, its SceneHook is not rehookable"
+            );
+        }
+    }
+}
+
+/// Remove [`SceneHooked`] from scenes which `A` asset was modified, so that
+/// [`run_hooks`] runs their hook again.
+///
+/// Added by [`HookPlugin::rehook_on_modified`](crate::HookPlugin::rehook_on_modified).
+pub fn rehook_modified_scenes<A: Asset>(
+    mut events: EventReader<AssetEvent<A>>,
+    hooked: Query<(Entity, &Handle<A>), With<SceneHooked>>,
+    mut cmds: Commands,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        for (entity, _) in hooked.iter().filter(|(_, handle)| handle.id() == *id) {
+            cmds.entity(entity).remove::<SceneHooked>();
         }
     }
 }
@@ -0,0 +1,107 @@
+//! Stable scene identifiers, see [`SceneId`].
+
+use bevy::app::{App, Plugin};
+use bevy::ecs::{
+    component::Component,
+    entity::Entity,
+    prelude::{RemovedComponents, With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, ResMut, Resource},
+};
+use bevy::reflect::Reflect;
+use bevy::utils::HashMap;
+
+#[cfg(feature = "reload")]
+use crate::reload;
+use crate::{hook_schedule, SceneHook, Systems};
+
+#[cfg(feature = "reload")]
+type Unidentified = (
+    bevy::ecs::query::Or<(With<SceneHook>, With<reload::Hook>)>,
+    Without<SceneId>,
+);
+#[cfg(not(feature = "reload"))]
+type Unidentified = (With<SceneHook>, Without<SceneId>);
+
+/// A unique identifier for a hooked scene, see [`SceneIds`].
+///
+#[cfg_attr(
+    feature = "reload",
+    doc = "Added by [`SceneIdPlugin`] to entities with a [`SceneHook`] or a [`reload::Hook`]."
+)]
+#[cfg_attr(
+    not(feature = "reload"),
+    doc = "Added by [`SceneIdPlugin`] to entities with a [`SceneHook`]."
+)]
+///
+/// The id stays the same for the whole lifetime of the scene, including
+#[cfg_attr(
+    feature = "reload",
+    doc = "across [`reload::State::MustReload`] reloads."
+)]
+#[cfg_attr(
+    not(feature = "reload"),
+    doc = "across `reload::State::MustReload` reloads."
+)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct SceneId(u64);
+
+/// The scene entity of each [`SceneId`].
+///
+/// Use this to refer to scenes by [`SceneId`] in other systems, rather than
+/// by `Entity`.
+#[derive(Resource, Debug, Default)]
+pub struct SceneIds {
+    scenes: HashMap<SceneId, Entity>,
+    next: u64,
+}
+impl SceneIds {
+    /// The scene entity with `id`, `None` if it was despawned.
+    #[must_use]
+    pub fn get(&self, id: SceneId) -> Option<Entity> {
+        self.scenes.get(&id).copied()
+    }
+    /// Iterate over all the scenes with a `SceneId` in the world.
+    pub fn iter(&self) -> impl Iterator<Item = (SceneId, Entity)> + '_ {
+        self.scenes.iter().map(|(id, entity)| (*id, *entity))
+    }
+}
+
+fn identify_scenes(
+    unidentified: Query<Entity, Unidentified>,
+    mut ids: ResMut<SceneIds>,
+    mut cmds: Commands,
+) {
+    for entity in &unidentified {
+        let id = SceneId(ids.next);
+        ids.next += 1;
+        ids.scenes.insert(id, entity);
+        cmds.entity(entity).insert(id);
+    }
+}
+
+fn forget_scenes(mut removed: RemovedComponents<SceneId>, mut ids: ResMut<SceneIds>) {
+    for entity in removed.read() {
+        ids.scenes.retain(|_, scene| *scene != entity);
+    }
+}
+
+/// Assign a [`SceneId`] to hooked scenes and keep [`SceneIds`] up to date.
+///
+/// The ids are assigned in the hook schedule, before [`Systems::SceneHookRunner`],
+/// so that hooks can read them. Add this plugin after [`HookPlugin`](crate::HookPlugin)
+/// to use the schedule set with [`HookPlugin::in_schedule`](crate::HookPlugin::in_schedule).
+pub struct SceneIdPlugin;
+impl Plugin for SceneIdPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = hook_schedule(app);
+        app.register_type::<SceneId>()
+            .init_resource::<SceneIds>()
+            .add_systems(
+                schedule,
+                (forget_scenes, identify_scenes).before(Systems::SceneHookRunner),
+            );
+    }
+}
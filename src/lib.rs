@@ -8,18 +8,184 @@
 //! 1. The very basic [`SceneHook`], it has a `hook` function field. It will
 //!    run once per entity within the scene, with the ability to add new
 //!    components to the entity and read its existing components.
-//! 2. The more advanced [`reload::Hook`]. Which works like [`SceneHook`],
+#![cfg_attr(
+    feature = "reload",
+    doc = "2. The more advanced [`reload::Hook`]. Which works like [`SceneHook`],"
+)]
+#![cfg_attr(
+    not(feature = "reload"),
+    doc = "2. The more advanced `reload::Hook`, with the `reload` feature. Which works like [`SceneHook`],"
+)]
 //!    but is aware of **reload** state, and also has access to the ECS `&World`
 //!    and the root `Entity` of the scene it is running for.
 //!
-//! The the respective documentation of [`SceneHook`] and [`reload::Hook`] for
+#![cfg_attr(
+    feature = "reload",
+    doc = "The the respective documentation of [`SceneHook`] and [`reload::Hook`] for"
+)]
+#![cfg_attr(
+    not(feature = "reload"),
+    doc = "The the respective documentation of [`SceneHook`] and `reload::Hook` for"
+)]
 //! usage examples.
+//!
+//! # Features
+//!
+//! - `gltf`: Add the `gltf` module, with hooks running on `Gltf` assets,
+//!   and `HookedSceneBundle::from_gltf`, to hook the default scene of a `Gltf`.
+//! - `gltf-extras`: Add `gltf::GltfExtrasExt`, to read glTF `extras`
+//!   custom properties as JSON. This enables `gltf`.
+//! - `pbr`: Add `SceneHook::map_materials`, to replace the `StandardMaterial`s
+//!   of scene entities.
+//! - `reload` (default): Add the `reload` module, with [`SceneHook`]s that
+//!   can respawn their scene. Disable default features to leave it out of
+//!   your build if you don't need it. [`SceneHook`] and [`HookPlugin`] are
+//!   always available.
+//!
+//! # Bring your own schedule
+//!
+#![cfg_attr(
+    feature = "reload",
+    doc = "[`HookPlugin`] and [`reload::Plugin`] add systems to the [`SpawnScene`]"
+)]
+#![cfg_attr(
+    not(feature = "reload"),
+    doc = "[`HookPlugin`] and `reload::Plugin` add systems to the [`SpawnScene`]"
+)]
+//! schedule. Use [`HookPlugin::in_schedule`] to run [`SceneHook`]s in another
+//! schedule. Otherwise, skip the plugins and add the systems yourself.
+//! They require no other setup, apart from registering [`SceneHooked`],
+//! [`HookRoot`], [`HookStats`], [`HookTimedOut`], [`InstanceReady`],
+#![cfg_attr(
+    feature = "reload",
+    doc = "[`RehookRequest`], [`HookProgress`], [`HookTotalEntities`] and the [`reload`] types if you want"
+)]
+#![cfg_attr(
+    not(feature = "reload"),
+    doc = "[`RehookRequest`], [`HookProgress`], [`HookTotalEntities`] and the `reload` types if you want"
+)]
+//! to reflect them.
+//!
+//! The hook systems must run after [`scene_spawner_system`], so that scene
+//! entities exist when the hooks run.
+//! Also register the events sent by hooks with `App::add_event`, as
+//! [`HookPlugin`] would: [`HookedEntity`], [`SceneHookCompleted`],
+//! [`SceneHookRejected`] and [`SceneHookTimedOut`]. bevy logs an error when
+//! sending unregistered events.
+//!
+//! - [`run_hooks`] and [`run_exclusive_hooks`] run [`SceneHook`]s, as well as
+//!   [`run_instance_hooks`] for [`HookInstanceExt::hook_instance`], and
+//!   [`poll_hook_tasks`] completes the [`SceneHook::new_deferred`] hooks.
+//! - [`time_out_hooks`] handles [`SceneHook::with_timeout`], and
+//!   [`use_fallback_scenes`] handles [`SceneHook::with_fallback`], it should
+//!   run before [`scene_spawner`](bevy::scene::scene_spawner) rather than after
+//!   [`scene_spawner_system`].
+//! - [`mark_ready_instances`] inserts [`InstanceReady`] and
+//!   [`HookTotalEntities`] on spawned scenes.
+//! - [`rehook_requested_scenes`] handles [`RehookRequest`]s, it should run
+//!   before [`run_hooks`].
+#![cfg_attr(
+    feature = "reload",
+    doc = "- [`reload::run_reloadable_hooks`] runs [`reload::Hook`]s."
+)]
+#![cfg_attr(
+    not(feature = "reload"),
+    doc = "- `reload::run_reloadable_hooks` runs `reload::Hook`s."
+)]
+//! - [`run_marker_hooks`] adds the hooks of [`HookAppExt::add_scene_hook`],
+//!   it should run before [`run_hooks`].
+//! - [`warn_unnamed_scenes`], [`log_scene_names`] and [`rehook_modified_scenes`]
+//!   are the optional systems added by [`HookPlugin::warn_unnamed_scenes`],
+//!   [`HookPlugin::with_diagnostics`] and [`HookPlugin::rehook_on_modified`].
+//! - With the `gltf` feature, `gltf::run_gltf_hooks` runs `GltfHook`s, marking
+//!   them with `gltf::GltfHooked`, and `gltf::spawn_gltf_scenes` handles
+//!   `HookedSceneBundle::from_gltf`. They should run before
+//!   [`scene_spawner`](bevy::scene::scene_spawner).
+//!
+//! ```rust
+//! use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+//! use bevy::scene::{scene_spawner, scene_spawner_system};
+//! use bevy_scene_hook::{poll_hook_tasks, run_exclusive_hooks, run_hooks, time_out_hooks};
+//! use bevy_scene_hook::{run_instance_hooks, use_fallback_scenes, HookInstance};
+//! use bevy_scene_hook::{mark_ready_instances, HookRoot, HookStats, HookTimedOut, InstanceReady};
+//! use bevy_scene_hook::{rehook_requested_scenes, HookedEntity, RehookRequest, SceneHooked};
+//! use bevy_scene_hook::{HookProgress, HookTotalEntities, SceneHookCompleted, SceneHookRejected, SceneHookTimedOut};
+//!
+//! #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+//! struct MySceneSchedule;
+//!
+//! let mut app = App::new();
+//! app.add_event::<HookedEntity>()
+//!     .add_event::<SceneHookCompleted>()
+//!     .add_event::<SceneHookRejected>()
+//!     .add_event::<SceneHookTimedOut>()
+//!     .register_type::<SceneHooked>()
+//!     .register_type::<HookRoot>()
+//!     .register_type::<HookStats>()
+//!     .register_type::<HookTimedOut>()
+//!     .register_type::<InstanceReady>()
+//!     .register_type::<RehookRequest>()
+//!     .register_type::<HookProgress>()
+//!     .register_type::<HookTotalEntities>()
+//!     .register_type::<HookInstance>()
+//!     .add_systems(
+//!         MySceneSchedule,
+//!         (
+//!             (rehook_requested_scenes, run_hooks, poll_hook_tasks).chain(),
+//!             run_exclusive_hooks,
+//!             run_instance_hooks,
+//!             time_out_hooks,
+//!             mark_ready_instances,
+//!         )
+//!             .after(scene_spawner_system),
+//!     )
+//!     .add_systems(MySceneSchedule, use_fallback_scenes.before(scene_spawner));
+//!
+//! // With the `reload` feature.
+//! #[cfg(feature = "reload")]
+//! {
+//!     use bevy_scene_hook::reload;
+//!
+//!     app.register_type::<reload::Hook>()
+//!         .register_type::<reload::State>()
+//!         .register_type::<reload::HookSpawned>()
+//!         .add_systems(
+//!             MySceneSchedule,
+//!             reload::run_reloadable_hooks.after(scene_spawner_system),
+//!         );
+//! }
+//! ```
+
+mod builder;
+#[cfg(feature = "gltf")]
+pub mod gltf;
 mod hook;
+mod id;
+mod node;
+#[cfg(feature = "reload")]
 pub mod reload;
 
-use bevy::{ecs::system::SystemParam, prelude::*, scene::scene_spawner_system};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::{EntityCommands, SystemParam};
+use bevy::prelude::*;
+use bevy::scene::{scene_spawner_system, InstanceId};
 
-pub use hook::{run_hooks, SceneHook, SceneHooked};
+pub use builder::SceneHookBuilder;
+pub use hook::{
+    log_hooked_entities, log_scene_names, mark_ready_instances, poll_hook_tasks,
+    rehook_modified_scenes, rehook_requested_scenes, run_exclusive_hooks, run_hook_on_instance,
+    run_hooks, run_instance_hooks, run_marker_hooks, scene_root, time_out_hooks,
+    use_fallback_scenes, warn_unnamed_scenes, DespawnHookedExt, HookChildrenExt, HookContext,
+    HookInserted, HookInstance, HookLog, HookProgress, HookRoot, HookStats, HookTask, HookTasks,
+    HookTemplates, HookTimedOut, HookTotalEntities, HookedEntity, InstanceReady, MarkerHooks,
+    RehookRequest, RemoveHookInsertedExt, SceneEntities, SceneHook, SceneHookCompleted,
+    SceneHookRejected, SceneHookTimedOut, SceneHooked, SceneVerdict,
+};
+pub use id::{SceneId, SceneIdPlugin, SceneIds};
+pub use node::{SceneNode, UnknownSceneNode};
+
+/// Derive [`SceneNode`] on fieldless enums, see the trait documentation.
+pub use bevy_scene_hook_macros::SceneNode;
 
 #[cfg(doctest)]
 #[doc = include_str!("../Readme.md")]
@@ -34,6 +200,67 @@ pub struct HookedSceneBundle {
     pub hook: SceneHook,
     pub scene: SceneBundle,
 }
+impl HookedSceneBundle {
+    /// Spawn `scene` with `hook`, with a default transform and visibility.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookedSceneBundle, SceneHook};
+    /// # #[derive(Component)] struct Card;
+    ///
+    /// fn load_scene(mut cmds: Commands, asset_server: Res<AssetServer>) {
+    ///     let hook = SceneHook::named("Card", |cmds| {
+    ///         cmds.insert(Card);
+    ///     });
+    ///     let scene = asset_server.load("scene.glb#Scene0");
+    ///     cmds.spawn(
+    ///         HookedSceneBundle::new(scene, hook)
+    ///             .with_transform(Transform::from_xyz(0.0, 1.0, 0.0)),
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn new(scene: Handle<Scene>, hook: SceneHook) -> Self {
+        Self { hook, scene: SceneBundle { scene, ..default() } }
+    }
+
+    /// Spawn the default scene of `gltf` with `hook`, once the `Gltf` is loaded.
+    ///
+    /// This avoids writing the `#Scene0` label of the scene asset path.
+    /// The scene of [`Gltf::default_scene`](bevy::gltf::Gltf::default_scene)
+    /// is spawned, or the first scene of the file if it has no default scene,
+    /// see [`gltf::SpawnDefaultScene`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookedSceneBundle, SceneHook};
+    /// # #[derive(Component)] struct Card;
+    ///
+    /// fn load_scene(mut cmds: Commands, asset_server: Res<AssetServer>) {
+    ///     let hook = SceneHook::named("Card", |cmds| {
+    ///         cmds.insert(Card);
+    ///     });
+    ///     cmds.spawn(HookedSceneBundle::from_gltf(asset_server.load("scene.glb"), hook));
+    /// }
+    /// ```
+    #[cfg(feature = "gltf")]
+    #[must_use]
+    pub fn from_gltf(gltf: Handle<bevy::gltf::Gltf>, hook: SceneHook) -> gltf::HookedGltfBundle {
+        let default_scene = gltf::SpawnDefaultScene;
+        gltf::HookedGltfBundle { hook, gltf, default_scene, spatial: default() }
+    }
+
+    /// Set the transform of the scene entity.
+    #[must_use]
+    pub const fn with_transform(mut self, transform: Transform) -> Self {
+        self.scene.transform = transform;
+        self
+    }
+}
 
 /// Bundle a [`SceneHook`] with dynamic scenes [`DynamicSceneBundle`] components.
 ///
@@ -44,11 +271,60 @@ pub struct HookedDynamicSceneBundle {
     pub hook: SceneHook,
     pub scene: DynamicSceneBundle,
 }
+impl HookedDynamicSceneBundle {
+    /// Spawn `scene` with `hook`, with a default transform and visibility.
+    #[must_use]
+    pub fn new(scene: Handle<DynamicScene>, hook: SceneHook) -> Self {
+        Self {
+            hook,
+            scene: DynamicSceneBundle { scene, ..default() },
+        }
+    }
+
+    /// Set the transform of the scene entity.
+    #[must_use]
+    pub const fn with_transform(mut self, transform: Transform) -> Self {
+        self.scene.transform = transform;
+        self
+    }
+}
+
+/// Add `scene` to the [`Assets<Scene>`] and spawn it with `hook`, returning
+/// the scene entity.
+///
+/// Use this for scenes built at runtime rather than loaded by the
+/// [`AssetServer`], such as scenes deserialized from bytes received over
+/// the network. The scene is spawned by bevy's scene spawner and hooked by
+/// [`run_hooks`] like a [`HookedSceneBundle`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::{spawn_hooked_from_scene, SceneHook};
+/// # #[derive(Component)] struct Card;
+///
+/// fn spawn_mod_scene(world: &mut World, scene: DynamicScene) {
+///     let registry = world.resource::<AppTypeRegistry>().clone();
+///     let Ok(scene) = Scene::from_dynamic_scene(&scene, &registry) else {
+///         return;
+///     };
+///     let hook = SceneHook::named("Card", |cmds| {
+///         cmds.insert(Card);
+///     });
+///     spawn_hooked_from_scene(scene, hook, world);
+/// }
+/// ```
+pub fn spawn_hooked_from_scene(scene: Scene, hook: SceneHook, world: &mut World) -> Entity {
+    let scene = world.resource_mut::<Assets<Scene>>().add(scene);
+    world.spawn(HookedSceneBundle::new(scene, hook)).id()
+}
 
 /// Convenience parameter to query if a scene marked with `M` has been loaded.
 #[derive(SystemParam)]
 pub struct HookedSceneState<'w, 's, M: Component> {
-    query: Query<'w, 's, (), (With<M>, With<SceneHooked>)>,
+    query: Query<'w, 's, Entity, (With<M>, With<SceneHooked>)>,
+    just_hooked: Query<'w, 's, (), (With<M>, Added<SceneHooked>)>,
 }
 impl<'w, 's, T: Component> HookedSceneState<'w, 's, T> {
     /// Whether any scene with `T` component has been loaded and its hook ran.
@@ -56,6 +332,32 @@ impl<'w, 's, T: Component> HookedSceneState<'w, 's, T> {
     pub fn is_loaded(&self) -> bool {
         self.query.iter().next().is_some()
     }
+
+    /// How many scenes with `T` component have been loaded and their hook ran.
+    ///
+    /// Useful to wait until several scenes are loaded.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.query.iter().count()
+    }
+
+    /// The scene entities with `T` component that have been loaded and their hook ran.
+    ///
+    /// Use this to handle each scene separately when several scenes share the
+    /// `T` marker.
+    pub fn loaded_roots(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.query.iter()
+    }
+
+    /// Whether a scene with `T` component was hooked since the last time
+    /// the system using this parameter ran.
+    ///
+    /// Contrary to [`HookedSceneState::is_loaded`], this is only `true` once
+    /// per hooked scene, use it for one-shot "on load" logic.
+    #[must_use]
+    pub fn just_loaded(&self) -> bool {
+        self.just_hooked.iter().next().is_some()
+    }
 }
 
 /// Convenience run criteria to query if a scene marked with `M` has been loaded.
@@ -64,22 +366,529 @@ pub fn is_scene_hooked<M: Component>(state: HookedSceneState<M>) -> bool {
     state.is_loaded()
 }
 
+/// Convenience run criteria to query if a scene marked with `M` was just loaded.
+///
+/// Contrary to [`is_scene_hooked`], this is only `true` once per hooked
+/// scene, see [`HookedSceneState::just_loaded`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::scene_just_hooked;
+///
+/// #[derive(Component)]
+/// struct Level;
+///
+/// fn start_music() {}
+/// App::new().add_systems(Update, start_music.run_if(scene_just_hooked::<Level>));
+/// ```
+#[allow(clippy::must_use_candidate)]
+pub fn scene_just_hooked<M: Component>(state: HookedSceneState<M>) -> bool {
+    state.just_loaded()
+}
+
+/// The schedule of the hook systems, set by [`HookPluginConfig`].
+#[derive(Resource)]
+struct HookSchedule(InternedScheduleLabel);
+
+/// The schedule of the hook systems, [`SpawnScene`] if [`HookPlugin`] was not added yet.
+pub(crate) fn hook_schedule(app: &App) -> InternedScheduleLabel {
+    let schedule = app.world.get_resource::<HookSchedule>();
+    schedule.map_or_else(|| SpawnScene.intern(), |schedule| schedule.0)
+}
+
+/// Extension trait on [`App`] for scene hook conveniences.
+pub trait HookAppExt {
+    /// Run `system` once, when the first scene marked with `M` is hooked.
+    ///
+    /// `system` is a [one-shot system](World::register_system) receiving
+    /// the scene's `Entity` as [`In`] input. It runs in the hook schedule,
+    /// after [`Systems::SceneHookRunner`], and never runs again, even if more
+    /// scenes marked with `M` are hooked later.
+    ///
+    /// The hook schedule is [`SpawnScene`], or the one set with
+    /// [`HookPlugin::in_schedule`]. Add the plugin before calling this,
+    /// otherwise `system` runs in `SpawnScene`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookAppExt, HookPlugin};
+    ///
+    /// #[derive(Component)]
+    /// struct Level;
+    ///
+    /// fn setup_level(In(level): In<Entity>, mut cmds: Commands) {
+    ///     cmds.entity(level).insert(Name::new("Current level"));
+    /// }
+    /// App::new()
+    ///     .add_plugins(HookPlugin)
+    ///     .run_when_hooked::<Level, _>(setup_level);
+    /// ```
+    fn run_when_hooked<M: Component, Marker>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), Marker> + 'static,
+    ) -> &mut Self;
+
+    /// Run `hook` on all spawned scenes with the `C` component.
+    ///
+    /// Use this to apply the same hook to scenes spawned from many places,
+    /// without adding a [`SceneHook`] to each of them. Spawn `C` with the
+    /// scene, for example in the same bundle as a [`SceneBundle`].
+    ///
+    /// The hooks are stored in the [`MarkerHooks`] resource. When `C` is added
+    /// to an entity, [`run_marker_hooks`] inserts the hook as a `SceneHook`
+    /// component, in the hook schedule, like
+    /// [`run_when_hooked`](HookAppExt::run_when_hooked). The scene is then
+    /// hooked like any scene with a `SceneHook`: it gets [`SceneHooked`], and
+    /// settings such as [`SceneHook::on_loaded`] apply. Adding several hooks
+    /// for the same `C` combines them, see [`MarkerHooks::insert`].
+    ///
+    /// # Interaction with [`SceneHook`] components
+    ///
+    /// If the entity already has a [`SceneHook`] component when `C` is added,
+    /// both hooks run, the `SceneHook` component first. The settings of both
+    /// are combined, see [`MarkerHooks::insert`].
+    /// Adding `C` to a scene already hooked doesn't hook it again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookAppExt, HookPlugin, SceneHook};
+    ///
+    /// #[derive(Component)]
+    /// struct Level;
+    /// #[derive(Component)]
+    /// struct Pile;
+    ///
+    /// fn spawn_level(mut cmds: Commands, assets: Res<AssetServer>) {
+    ///     let scene = assets.load("level.glb#Scene0");
+    ///     cmds.spawn((SceneBundle { scene, ..default() }, Level));
+    /// }
+    /// App::new()
+    ///     .add_plugins(HookPlugin)
+    ///     .add_scene_hook::<Level>(SceneHook::named("Pile", |cmds| { cmds.insert(Pile); }))
+    ///     .add_systems(Startup, spawn_level);
+    /// ```
+    fn add_scene_hook<C: Component>(&mut self, hook: SceneHook) -> &mut Self;
+}
+impl HookAppExt for App {
+    fn run_when_hooked<M: Component, Marker>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), Marker> + 'static,
+    ) -> &mut Self {
+        let system = self.world.register_system(system);
+        let run_once = move |mut ran: Local<bool>,
+                             hooked: Query<Entity, (With<M>, With<SceneHooked>)>,
+                             mut cmds: Commands| {
+            if *ran {
+                return;
+            }
+            if let Some(scene) = hooked.iter().next() {
+                *ran = true;
+                cmds.run_system_with_input(system, scene);
+            }
+        };
+        let schedule = hook_schedule(self);
+        self.add_systems(schedule, run_once.after(Systems::SceneHookRunner))
+    }
+
+    fn add_scene_hook<C: Component>(&mut self, hook: SceneHook) -> &mut Self {
+        let mut hooks = self.world.get_resource_or_insert_with(MarkerHooks::default);
+        let new_marker = !hooks.contains::<C>();
+        hooks.insert::<C>(hook);
+        if new_marker {
+            let run_marker_hooks = run_marker_hooks::<C>
+                .before(run_hooks)
+                .before(run_exclusive_hooks);
+            let schedule = hook_schedule(self);
+            self.add_systems(
+                schedule,
+                run_marker_hooks
+                    .in_set(Systems::SceneHookRunner)
+                    .after(scene_spawner_system),
+            );
+        }
+        self
+    }
+}
+
+/// Extension trait on [`Commands`] to hook scenes spawned without a bundle.
+pub trait HookInstanceExt {
+    /// Run `hook` on `instance` once it is ready.
+    ///
+    /// Use this when spawning scenes with [`SceneSpawner::spawn`] rather than
+    /// [`HookedSceneBundle`]. This spawns a new entity holding `hook` and the
+    /// [`HookInstance`], it is the scene entity of `instance`: `hook` runs on it
+    /// and it gets the [`SceneHooked`] marker. Hooks are ran by
+    /// [`run_instance_hooks`], as with [`run_hook_on_instance`], so
+    /// [`SceneHook::on_loaded`] runs once the hook is done with the scene.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookInstanceExt, SceneHook};
+    /// # #[derive(Component)] struct Pile;
+    ///
+    /// fn load_scene(mut cmds: Commands, mut spawner: ResMut<SceneSpawner>, assets: Res<AssetServer>) {
+    ///     let instance = spawner.spawn(assets.load("scene.glb#Scene0"));
+    ///     cmds.hook_instance(instance, SceneHook::named("Pile", |cmds| { cmds.insert(Pile); }));
+    /// }
+    /// ```
+    fn hook_instance(&mut self, instance: InstanceId, hook: SceneHook) -> EntityCommands<'_>;
+}
+impl HookInstanceExt for Commands<'_, '_> {
+    fn hook_instance(&mut self, instance: InstanceId, hook: SceneHook) -> EntityCommands<'_> {
+        self.spawn((hook, HookInstance(instance)))
+    }
+}
+
 /// Systems defined in the [`bevy_scene_hook`](crate) crate (this crate).
-#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+///
+/// Use [`HookPlugin::configure_sets`] to order them relative to other plugins.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet, Reflect)]
 pub enum Systems {
     /// System running the hooks.
     SceneHookRunner,
 }
 
 /// Plugin to run hooks associated with spawned scenes.
+///
+/// Add `HookPlugin` to run hooks with the default configuration. Its methods
+/// return a [`HookPluginConfig`], which is the same plugin with a different
+/// configuration.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::HookPlugin;
+///
+/// App::new().add_plugins(HookPlugin.in_schedule(PreUpdate).spawn_scenes());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
 pub struct HookPlugin;
+impl HookPlugin {
+    /// See [`HookPluginConfig::in_schedule`].
+    #[must_use]
+    pub fn in_schedule(self, schedule: impl ScheduleLabel) -> HookPluginConfig {
+        HookPluginConfig::from(self).in_schedule(schedule)
+    }
+
+    /// See [`HookPluginConfig::spawn_scenes`].
+    #[must_use]
+    pub fn spawn_scenes(self) -> HookPluginConfig {
+        HookPluginConfig::from(self).spawn_scenes()
+    }
+
+    /// See [`HookPluginConfig::configure_sets`].
+    #[must_use]
+    pub fn configure_sets(self, configure: fn(&mut App)) -> HookPluginConfig {
+        HookPluginConfig::from(self).configure_sets(configure)
+    }
+
+    /// See [`HookPluginConfig::with_logging`].
+    #[must_use]
+    pub fn with_logging(self) -> HookPluginConfig {
+        HookPluginConfig::from(self).with_logging()
+    }
+
+    /// See [`HookPluginConfig::with_diagnostics`].
+    #[must_use]
+    pub fn with_diagnostics(self) -> HookPluginConfig {
+        HookPluginConfig::from(self).with_diagnostics()
+    }
+
+    /// See [`HookPluginConfig::warn_unnamed_scenes`].
+    #[must_use]
+    pub fn warn_unnamed_scenes(self) -> HookPluginConfig {
+        HookPluginConfig::from(self).warn_unnamed_scenes()
+    }
+
+    /// See [`HookPluginConfig::rehook_on_modified`].
+    #[must_use]
+    pub fn rehook_on_modified(self) -> HookPluginConfig {
+        HookPluginConfig::from(self).rehook_on_modified()
+    }
+}
 impl Plugin for HookPlugin {
     fn build(&self, app: &mut App) {
+        HookPluginConfig::from(*self).build(app);
+    }
+}
+
+/// [`HookPlugin`] with a non-default configuration.
+///
+/// Create it with the methods of [`HookPlugin`], and add it to the app
+/// instead of `HookPlugin`. It counts as `HookPlugin` for bevy: adding both
+/// panics, as adding `HookPlugin` twice would.
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools /* independent builder options */)]
+pub struct HookPluginConfig {
+    warn_unnamed_scenes: bool,
+    rehook_on_modified: bool,
+    schedule: InternedScheduleLabel,
+    configure_sets: Option<fn(&mut App)>,
+    logging: bool,
+    spawn_scenes: bool,
+    diagnostics: bool,
+}
+impl From<HookPlugin> for HookPluginConfig {
+    fn from(HookPlugin: HookPlugin) -> Self {
+        Self {
+            warn_unnamed_scenes: false,
+            rehook_on_modified: false,
+            schedule: SpawnScene.intern(),
+            configure_sets: None,
+            logging: false,
+            spawn_scenes: false,
+            diagnostics: false,
+        }
+    }
+}
+impl HookPluginConfig {
+    /// Add the hook systems to `schedule` instead of [`SpawnScene`].
+    ///
+    /// The systems are still in the [`Systems::SceneHookRunner`] set and
+    /// run after [`scene_spawner_system`], if `schedule` contains it.
+    /// Use [`HookPlugin::spawn_scenes`] to add it to `schedule`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// use bevy_scene_hook::HookPlugin;
+    ///
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySceneSchedule;
+    ///
+    /// App::new().add_plugins(HookPlugin.in_schedule(MySceneSchedule));
+    /// ```
+    #[must_use]
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Also spawn scenes in the hook schedule set with [`HookPlugin::in_schedule`].
+    ///
+    /// bevy spawns scenes in [`SpawnScene`], after [`Update`]. To hook scenes
+    /// the same frame they are spawned in an earlier schedule, such as
+    /// [`PreUpdate`], bevy's scene spawning systems must run in that schedule
+    /// before the hooks. This adds [`scene_spawner`](bevy::scene::scene_spawner)
+    /// and [`scene_spawner_system`] to the hook schedule. They still run in
+    /// `SpawnScene` as well.
+    ///
+    /// Systems spawning scenes should run before `scene_spawner`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy::scene::scene_spawner;
+    /// use bevy_scene_hook::HookPlugin;
+    ///
+    /// fn spawn_level(mut cmds: Commands, assets: Res<AssetServer>) {
+    ///     cmds.spawn(SceneBundle { scene: assets.load("level.glb#Scene0"), ..default() });
+    /// }
+    /// App::new()
+    ///     .add_plugins(HookPlugin.in_schedule(PreUpdate).spawn_scenes())
+    ///     .add_systems(PreUpdate, spawn_level.before(scene_spawner));
+    /// ```
+    #[must_use]
+    pub fn spawn_scenes(mut self) -> Self {
+        self.spawn_scenes = true;
+        self
+    }
+
+    /// Run `configure` when adding the plugin, to order [`Systems`] relative
+    /// to the systems of other plugins.
+    ///
+    /// `configure` runs at the end of [`HookPlugin`]'s `build`, after the
+    /// hook systems are added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookPlugin, Systems};
+    ///
+    /// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct PhysicsSetup;
+    ///
+    /// App::new().add_plugins(HookPlugin.configure_sets(|app| {
+    ///     app.configure_sets(SpawnScene, Systems::SceneHookRunner.before(PhysicsSetup));
+    /// }));
+    /// ```
+    #[must_use]
+    pub fn configure_sets(mut self, configure: fn(&mut App)) -> Self {
+        self.configure_sets = Some(configure);
+        self
+    }
+
+    /// Record hooked entities in the [`HookLog`] resource.
+    ///
+    /// By default, the log only holds the entities hooked in the last frame,
+    /// set [`HookLog::accumulate`] to keep all entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::{HookLog, HookPlugin};
+    ///
+    /// fn print_hooked(log: Res<HookLog>) {
+    ///     for hooked in log.entries() {
+    ///         println!("Hooked {:?} of scene {:?}", hooked.name, hooked.root);
+    ///     }
+    /// }
+    /// App::new()
+    ///     .add_plugins(HookPlugin.with_logging())
+    ///     .add_systems(Update, print_hooked);
+    /// ```
+    #[must_use]
+    pub fn with_logging(mut self) -> Self {
+        self.logging = true;
+        self
+    }
+
+    /// Log the [`Name`]s of the entities of each hooked scene, at `debug` level.
+    ///
+    /// When a name-based hook does nothing, it is usually because the
+    /// entity names differ from the names in the hook, for example in case
+    /// or with a `.001` suffix added by the 3D editor. Copy the exact names
+    /// from the logs. The logs are emitted by [`log_scene_names`].
+    #[must_use]
+    pub fn with_diagnostics(mut self) -> Self {
+        self.diagnostics = true;
+        self
+    }
+
+    /// Log a warning when a hooked scene has entities, but none of them have
+    /// a [`Name`] component.
+    ///
+    /// Name-based hooks silently do nothing on such scenes, this usually means
+    /// the asset was exported or imported without node names.
+    #[must_use]
+    pub fn warn_unnamed_scenes(mut self) -> Self {
+        self.warn_unnamed_scenes = true;
+        self
+    }
+
+    /// Run again the [`SceneHook`] of all scenes which asset was modified,
+    /// for example when hot reloading assets with the asset server's `file_watcher`.
+    ///
+    /// This only re-applies the hook, the scene is **not** respawned:
+    /// - Bevy updates in-place instances of a modified [`DynamicScene`], then
+    ///   the hook runs on the updated entities.
+    /// - Bevy doesn't update instances of a modified [`Scene`], so the hook runs
+    #[cfg_attr(
+        feature = "reload",
+        doc = "  again on the existing entities. Use [`reload::Hook`] to respawn them."
+    )]
+    #[cfg_attr(
+        not(feature = "reload"),
+        doc = "  again on the existing entities. Use `reload::Hook` to respawn them."
+    )]
+    ///
+    /// Scenes hooked with [`SceneHook::no_marker`] are never hooked again.
+    #[must_use]
+    pub fn rehook_on_modified(mut self) -> Self {
+        self.rehook_on_modified = true;
+        self
+    }
+}
+impl Plugin for HookPluginConfig {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Systems>()
+            .register_type::<SceneHooked>()
+            .register_type::<HookRoot>()
+            .register_type::<HookStats>()
+            .register_type::<HookTimedOut>()
+            .register_type::<InstanceReady>()
+            .register_type::<RehookRequest>()
+            .register_type::<HookProgress>()
+            .register_type::<HookTotalEntities>()
+            .register_type::<HookInstance>()
+            .add_event::<HookedEntity>()
+            .add_event::<SceneHookCompleted>()
+            .add_event::<SceneHookRejected>()
+            .add_event::<SceneHookTimedOut>()
+            .insert_resource(HookSchedule(self.schedule))
+            .add_systems(
+                self.schedule,
+                (
+                    run_hooks,
+                    run_exclusive_hooks,
+                    poll_hook_tasks.after(run_hooks),
+                    time_out_hooks,
+                    mark_ready_instances.before(run_hooks),
+                    run_instance_hooks,
+                )
+                    .in_set(Systems::SceneHookRunner)
+                    .after(scene_spawner_system),
+            )
+            .add_systems(
+                self.schedule,
+                rehook_requested_scenes.before(Systems::SceneHookRunner),
+            );
         app.add_systems(
-            SpawnScene,
-            run_hooks
-                .in_set(Systems::SceneHookRunner)
-                .after(scene_spawner_system),
+            self.schedule,
+            use_fallback_scenes.before(bevy::scene::scene_spawner),
         );
+        if self.spawn_scenes && self.schedule != SpawnScene.intern() {
+            app.add_systems(
+                self.schedule,
+                (bevy::scene::scene_spawner, scene_spawner_system).chain(),
+            );
+        }
+        #[cfg(feature = "gltf")]
+        app.register_type::<gltf::GltfHooked>().add_systems(
+            self.schedule,
+            (
+                gltf::run_gltf_hooks.in_set(Systems::SceneHookRunner),
+                gltf::spawn_gltf_scenes,
+            )
+                .before(bevy::scene::scene_spawner),
+        );
+        if self.rehook_on_modified {
+            app.add_systems(
+                self.schedule,
+                (
+                    rehook_modified_scenes::<Scene>,
+                    rehook_modified_scenes::<DynamicScene>,
+                )
+                    .before(Systems::SceneHookRunner),
+            );
+        }
+        if self.warn_unnamed_scenes {
+            app.add_systems(
+                self.schedule,
+                warn_unnamed_scenes.after(Systems::SceneHookRunner),
+            );
+        }
+        if self.diagnostics {
+            app.add_systems(
+                self.schedule,
+                log_scene_names.after(Systems::SceneHookRunner),
+            );
+        }
+        if self.logging {
+            app.init_resource::<HookLog>().add_systems(
+                self.schedule,
+                log_hooked_entities.after(Systems::SceneHookRunner),
+            );
+        }
+        if let Some(configure_sets) = self.configure_sets {
+            configure_sets(app);
+        }
+    }
+
+    fn name(&self) -> &str {
+        std::any::type_name::<HookPlugin>()
     }
 }
@@ -0,0 +1,47 @@
+//! Typed scene node names, see [`SceneNode`].
+
+use std::fmt;
+
+/// A type representing the [`Name`](bevy::core::Name)s of entities in a scene.
+///
+/// Use `#[derive(SceneNode)]` on a fieldless enum to implement it, together with
+/// [`FromStr`](std::str::FromStr). Each variant matches the entity name equal to the
+/// variant's name, use `#[name = "..."]` on a variant to match a different name.
+///
+/// Combined with [`SceneHook::match_node`](crate::SceneHook::match_node), this
+/// lets the compiler check you handle all the nodes you care about.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_scene_hook::SceneNode;
+///
+/// #[derive(SceneNode, Debug, PartialEq)]
+/// enum Node {
+///     Yellow,
+///     #[name = "Cube.001"]
+///     Cube,
+/// }
+/// assert_eq!(Node::from_name("Yellow"), Some(Node::Yellow));
+/// assert_eq!("Cube.001".parse(), Ok(Node::Cube));
+/// assert_eq!(Node::Cube.as_str(), "Cube.001");
+/// assert_eq!(Node::from_name("Cube"), None);
+/// ```
+pub trait SceneNode: Sized {
+    /// The node matching the entity `name`, `None` if no nodes match.
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// The entity name of this node.
+    fn as_str(&self) -> &'static str;
+}
+
+/// Error returned by the `FromStr` implementation of [`SceneNode`]s when the
+/// name doesn't match any node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownSceneNode;
+impl fmt::Display for UnknownSceneNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("name doesn't match any scene node")
+    }
+}
+impl std::error::Error for UnknownSceneNode {}
@@ -1,12 +1,24 @@
 //! Defines reloading [`Hook`]s and supporting system.
 
-use bevy::ecs::system::{Command, EntityCommands};
+use bevy::asset::{AssetId, AssetPath};
+use bevy::ecs::component::ComponentInfo;
+use bevy::ecs::entity::{EntityHashMap, EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::{Command, CommandQueue, EntityCommands, SystemParam};
+use bevy::log::{info_span, trace_span};
 use bevy::prelude::{
-    AssetServer, Bundle, Commands, Component, DespawnRecursiveExt, Entity, EntityRef, Handle,
-    IntoSystemConfigs, Plugin as BevyPlugin, Query, Reflect, Res, Scene,
-    SceneBundle as BevySceneBundle, SceneSpawner, World,
+    AppTypeRegistry, AssetEvent, AssetServer, Assets, BuildChildren, BuildWorldChildren, Bundle,
+    Children, Commands, Component, DespawnRecursiveExt, Entity, EntityRef, EventReader, Handle,
+    IntoSystemConfigs, Mut, Name, Parent, Plugin as BevyPlugin, Query, Reflect, ReflectComponent,
+    Res, Resource, Scene, SceneBundle as BevySceneBundle, SceneSpawner, Transform, With, World,
 };
+use bevy::reflect::TypeInfo;
 use bevy::scene::SceneInstance;
+use bevy::utils::HashMap;
+use std::any::TypeId;
+use std::sync::Arc;
+
+pub use crate::builder::HookBuilder;
 
 /// Bundle a reload [`Hook`] with the standard [`bevy::prelude::SceneBundle`] components.
 #[derive(Bundle)]
@@ -15,13 +27,33 @@ pub struct SceneBundle {
     pub reload: Hook,
     pub scene: BevySceneBundle,
 }
+impl SceneBundle {
+    /// Spawn `scene` with the `reload` hook, with a default transform and visibility.
+    #[must_use]
+    pub fn new(scene: Handle<Scene>, reload: Hook) -> Self {
+        let scene = BevySceneBundle { scene, ..Default::default() };
+        Self { reload, scene }
+    }
+
+    /// Set the transform of the scene entity.
+    #[must_use]
+    pub const fn with_transform(mut self, transform: Transform) -> Self {
+        self.scene.transform = transform;
+        self
+    }
+}
+
+type DynHookFn = dyn Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static;
+type DynRootFn = dyn Fn(&mut EntityCommands, &World) + Send + Sync + 'static;
+type DynFilterFn = dyn Fn(&EntityRef) -> bool + Send + Sync + 'static;
+type DynStateFn = dyn Fn(State, State) + Send + Sync + 'static;
+/// Components saved by [`Hook::retain`], per entity [`Name`].
+type Retained = HashMap<String, Vec<Box<dyn Reflect>>>;
 
 /// A newtype for a dynamic `Fn` that can be run as a hook.
 ///
 /// This is to allow `#[reflect(ignore)]`.
-pub struct HookFn(
-    pub Box<dyn Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static>,
-);
+pub struct HookFn(pub Box<DynHookFn>);
 
 impl Default for HookFn {
     fn default() -> Self {
@@ -29,11 +61,104 @@ impl Default for HookFn {
     }
 }
 
+/// The data available to a [`Hook`] created with [`Hook::new_with_context`].
+#[non_exhaustive]
+pub struct Context<'w> {
+    /// The world, as it was before running the hook on any entity of the scene.
+    pub world: &'w World,
+    /// The `Entity` of the scene the hooked entity is part of.
+    pub root: Entity,
+    /// The app's type registry, to inspect or build components through reflection.
+    pub registry: &'w AppTypeRegistry,
+    /// How many times the scene was reloaded, see [`Hook::generation`].
+    pub generation: u32,
+    /// The path of the scene asset, if it was loaded from a file.
+    ///
+    /// Use it to specialize a hook shared by several scene files.
+    pub path: Option<&'w AssetPath<'static>>,
+}
+impl Context<'_> {
+    /// Whether the hook runs on a reloaded scene, rather than on its first load.
+    ///
+    /// Use it to skip expensive setup already done on the first load.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::reload;
+    ///
+    /// let hook = reload::Hook::new_with_context(|_, cmds, ctx| {
+    ///     if !ctx.is_reload() {
+    ///         ctx.insert_on_root(cmds, Name::new("Level"));
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub const fn is_reload(&self) -> bool {
+        self.generation > 0
+    }
+
+    /// Insert `bundle` on the scene entity, [`Context::root`].
+    ///
+    /// `cmds` is the `EntityCommands` the hook received.
+    pub fn insert_on_root(&self, cmds: &mut EntityCommands, bundle: impl Bundle) {
+        cmds.commands().entity(self.root).insert(bundle);
+    }
+
+    /// Spawn `bundle` as a child of the hooked entity, marked as [`HookSpawned`].
+    ///
+    /// `cmds` is the `EntityCommands` the hook received.
+    pub fn spawn_child(&self, cmds: &mut EntityCommands, bundle: impl Bundle) -> Entity {
+        let child = cmds.commands().spawn((bundle, HookSpawned(self.root))).id();
+        cmds.add_child(child);
+        child
+    }
+
+    /// Insert `bundle` on the parent of the scene entity, typically the
+    /// gameplay entity the scene was spawned under.
+    ///
+    /// `cmds` is the `EntityCommands` the hook received.
+    ///
+    /// Does nothing and returns `false` if the scene entity has no parent.
+    pub fn insert_on_scene_parent(&self, cmds: &mut EntityCommands, bundle: impl Bundle) -> bool {
+        let Some(parent) = self.world.get::<Parent>(self.root) else {
+            return false;
+        };
+        cmds.commands().entity(parent.get()).insert(bundle);
+        true
+    }
+}
+
+/// Marks an entity spawned by a [`Hook`], with the scene entity it belongs to.
+///
+/// Entities with `HookSpawned` are despawned recursively with the scene on
+/// [`State::MustReload`] and [`State::MustDelete`], even if they are not
+/// descendants of the scene entities. Otherwise, they would be left behind
+/// on each reload.
+///
+/// Use [`Context::spawn_child`] to spawn entities with `HookSpawned`, or add
+/// it yourself to the entities you spawn in [`Hook::hook`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct HookSpawned(pub Entity);
+impl MapEntities for HookSpawned {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
 /// Controls loading and reloading of scenes with a hook.
-#[derive(PartialEq, Eq, Clone, Copy, Debug, Reflect)]
+#[derive(PartialEq, Eq, Clone, Debug, Reflect)]
+#[non_exhaustive]
 pub enum State {
     /// The scene's entites are not yet added to the `World`.
     Loading,
+    /// The scene's entities were despawned due to [`State::MustReload`], and
+    /// the respawned scene's entities are not yet added to the `World`.
+    ///
+    /// This is like [`State::Loading`], but only after a reload.
+    Reloading,
     /// The scene's entities are now in the `World` and its entities have the
     /// components added by the scene's [`Hook::hook`].
     Hooked,
@@ -42,12 +167,25 @@ pub enum State {
     /// reloaded, running [`Hook::hook`] again.
     ///
     /// The spawned scene is loaded using [`Handle::path`] of the entitie's `Handle<Scene>`
-    /// component.
+    /// component, or is the scene passed to [`Hook::reload_with`]. If the scene
+    /// has no path, for example when it was added to `Assets<Scene>` at runtime
+    /// or the handle is a weak handle created from an `AssetId`, the same
+    /// `Handle<Scene>` is spawned again.
+    ///
+    /// On the scene entity, only the `Handle<Scene>` is replaced and the
+    /// `SceneInstance` removed, bevy then adds a new `SceneInstance`. Its
+    /// `Children` also change, since the scene's entities are respawned.
+    /// Other components, such as `Transform` and `Visibility`, are kept as is.
     MustReload,
     /// The scene's entities, whether they are its direct children or were
     /// unparented are to be despawned next time [`run_reloadable_hooks`] runs, the scene
     /// entity itself will also be deleted.
     MustDelete,
+    /// The scene entity with this [`Name`] and its descendants are to be
+    /// despawned next time [`run_reloadable_hooks`] runs, and respawned from
+    /// the scene asset, running [`Hook::hook`] on the respawned entities.
+    /// The rest of the scene is kept as is, see [`Hook::reload_node`].
+    MustReloadNode(Name),
 }
 /// A variant of [`crate::SceneHook`] that allows for reloading.
 ///
@@ -76,11 +214,49 @@ pub struct Hook {
     /// - [`& World`]: The world
     /// - [`Entity`]: The `Entity` of the scene this entity is part of. May be useful
     ///   in combination with `&World` to get components of the scene.
+    ///
+    /// The hook runs on the scene entities in the order of
+    /// [`SceneSpawner::iter_instance_entities`], which only depends on the
+    /// scene asset. So after a reload of the same asset, it runs in the same
+    /// order as on the first load, and entities spawned by the hook, such as
+    /// children added to the scene entity, are spawned in the same order.
     #[reflect(ignore)]
     pub hook: HookFn,
+    #[reflect(ignore)]
+    filter: Option<Box<DynFilterFn>>,
+    #[reflect(ignore)]
+    before: Option<Box<DynRootFn>>,
+    #[reflect(ignore)]
+    on_loaded: Option<Box<DynRootFn>>,
+    #[reflect(ignore)]
+    on_despawn: Option<Box<DynRootFn>>,
+    #[reflect(ignore)]
+    on_delete: Option<Box<DynRootFn>>,
+    #[reflect(ignore)]
+    on_state_change: Option<Box<DynStateFn>>,
+    #[reflect(ignore)]
+    skip_unchanged: bool,
+    /// The content of the scene asset when it was last hooked.
+    #[reflect(ignore)]
+    content: Option<Arc<SceneContent>>,
+    /// Whether the pending reload was requested by [`reload_modified_scenes`].
+    #[reflect(ignore)]
+    asset_modified: bool,
+    /// How many times the scene was reloaded.
+    generation: u32,
+    #[reflect(ignore)]
+    retain: Vec<TypeId>,
+    /// The components saved before despawning the scene for a reload.
+    #[reflect(ignore)]
+    retained: Retained,
+    /// The scene to load instead of the current one on the next reload.
+    #[reflect(ignore)]
+    reload_with: Option<Handle<Scene>>,
 }
 impl Hook {
     /// Create a new `Hook` for a **loading** scene with provided `hook`.
+    ///
+    /// Use [`HookBuilder`] to run a different hook per entity [`Name`].
     pub fn new<F>(hook: F) -> Self
     where
         F: Fn(&EntityRef, &mut EntityCommands, &World, Entity) + Send + Sync + 'static,
@@ -88,58 +264,632 @@ impl Hook {
         Self {
             state: State::Loading,
             hook: HookFn(Box::new(hook)),
+            filter: None,
+            before: None,
+            on_loaded: None,
+            on_despawn: None,
+            on_delete: None,
+            on_state_change: None,
+            skip_unchanged: false,
+            content: None,
+            asset_modified: false,
+            generation: 0,
+            retain: Vec::new(),
+            retained: Retained::default(),
+            reload_with: None,
+        }
+    }
+
+    /// Create a new `Hook` for a **loading** scene with a `hook` accepting a [`Context`].
+    ///
+    /// Prefer this to [`Hook::new`] when you need the [`AppTypeRegistry`], to
+    /// avoid fetching it from the `World` yourself.
+    ///
+    /// # Panics
+    ///
+    /// When running the hook if the `World` has no [`AppTypeRegistry`] resource.
+    pub fn new_with_context<F>(hook: F) -> Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands, &Context) + Send + Sync + 'static,
+    {
+        Self::new(move |entity, cmds, world, root| {
+            let registry = world.resource::<AppTypeRegistry>();
+            let generation = world.get::<Self>(root).map_or(0, Self::generation);
+            let path = world.get::<Handle<Scene>>(root).and_then(Handle::path);
+            let ctx = Context { world, root, registry, generation, path };
+            hook(entity, cmds, &ctx);
+        })
+    }
+
+    /// Only run [`Hook::hook`] on scene entities for which `filter` returns `true`.
+    #[must_use]
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&EntityRef) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Run `before` on the scene entity, each time the scene is (re)loaded,
+    /// before running [`Hook::hook`] on the scene entities.
+    #[must_use]
+    pub fn before<F>(mut self, before: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World) + Send + Sync + 'static,
+    {
+        self.before = Some(Box::new(before));
+        self
+    }
+
+    /// Run `on_loaded` on the scene entity, each time the scene is (re)loaded,
+    /// after running [`Hook::hook`] on the scene entities.
+    #[must_use]
+    pub fn on_loaded<F>(mut self, on_loaded: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World) + Send + Sync + 'static,
+    {
+        self.on_loaded = Some(Box::new(on_loaded));
+        self
+    }
+
+    /// Run `on_despawn` on the scene entity before despawning the scene's
+    /// entities, due to [`State::MustReload`] or [`State::MustDelete`].
+    ///
+    /// The scene entities are still present in the `World` when `on_despawn` runs.
+    #[must_use]
+    pub fn on_despawn<F>(mut self, on_despawn: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World) + Send + Sync + 'static,
+    {
+        self.on_despawn = Some(Box::new(on_despawn));
+        self
+    }
+
+    /// Run `on_delete` on the scene entity before deleting the scene, due to
+    /// [`State::MustDelete`].
+    ///
+    /// Contrary to [`Hook::on_despawn`], it doesn't run on reloads. Use it for
+    /// things that should only happen when the scene is gone for good, such
+    /// as spawning a death effect or saving a score. It runs before
+    /// `on_despawn`, and the scene entities are still present in the `World`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_scene_hook::reload;
+    ///
+    /// #[derive(Component)]
+    /// struct Explosion;
+    ///
+    /// let hook = reload::Hook::new(|_, _, _, _| {}).on_delete(|cmds, world| {
+    ///     let transform = world.get::<Transform>(cmds.id()).copied().unwrap_or_default();
+    ///     cmds.commands().spawn((Explosion, TransformBundle::from_transform(transform)));
+    /// });
+    /// ```
+    #[must_use]
+    pub fn on_delete<F>(mut self, on_delete: F) -> Self
+    where
+        F: Fn(&mut EntityCommands, &World) + Send + Sync + 'static,
+    {
+        self.on_delete = Some(Box::new(on_delete));
+        self
+    }
+
+    /// Run `on_state_change` with the old and new [`State`] each time
+    /// this crate changes [`Hook::state`], in [`run_reloadable_hooks`],
+    /// [`AutoReloadPlugin`], [`ReloadSceneExt`] or methods such as
+    /// [`Hook::request_reload`].
+    ///
+    /// For example, `on_state_change` is called with `(MustReload, Reloading)`
+    /// after despawning the scene for a reload, then with `(Reloading, Hooked)`
+    /// once it is respawned and hooked.
+    ///
+    /// This is not called when you set [`Hook::state`] yourself.
+    #[must_use]
+    pub fn on_state_change<F>(mut self, on_state_change: F) -> Self
+    where
+        F: Fn(State, State) + Send + Sync + 'static,
+    {
+        self.on_state_change = Some(Box::new(on_state_change));
+        self
+    }
+
+    /// On [`State::MustReload`], do not reload the scene if its asset has the
+    /// same content as when it was last hooked, go straight back to [`State::Hooked`].
+    ///
+    /// This avoids needless reloads when a file watcher reports a change,
+    /// but the scene file stayed the same, for example when it is touched
+    /// without being edited.
+    ///
+    /// The content of the scene asset is compared through the reflected
+    /// components of its entities, with [`Reflect::reflect_partial_eq`].
+    /// Scenes with values that can't be compared are always reloaded.
+    ///
+    /// Only reloads requested by [`reload_modified_scenes`], added with
+    /// [`AutoReloadPlugin`], are skipped. Reloads requested manually, by
+    /// setting [`Hook::state`], with [`Hook::request_reload`] or
+    /// [`Hook::reload_with`], always reload.
+    #[must_use]
+    pub fn skip_unchanged_reloads(mut self) -> Self {
+        self.skip_unchanged = true;
+        self
+    }
+
+    /// Reload the scene as `scene`, instead of its current scene asset.
+    ///
+    /// This sets [`Hook::state`] to [`State::MustReload`]. The scene entities
+    /// are despawned and `scene` is spawned in their place, keeping the scene
+    /// entity and this `Hook`. The scene entity's `Handle<Scene>` is replaced
+    /// by `scene`, so later reloads reload `scene`.
+    ///
+    /// This is useful to swap a scene for another one, such as a different
+    /// level of detail.
+    pub fn reload_with(&mut self, scene: Handle<Scene>) {
+        self.set_state(State::MustReload);
+        self.reload_with = Some(scene);
+    }
+
+    /// Set [`Hook::state`] to [`State::MustReload`], only if the scene is [`State::Hooked`].
+    ///
+    /// Returns whether the reload was requested. Unlike setting `state`
+    /// directly, this does nothing while the scene is still loading or
+    /// reloading, so it is safe to call several times in a row, for example
+    /// from a "reload" button.
+    pub fn request_reload(&mut self) -> bool {
+        let hooked = self.state == State::Hooked;
+        if hooked {
+            self.set_state(State::MustReload);
+        }
+        hooked
+    }
+
+    /// Reload only the scene entity with the `name` [`Name`] and its descendants.
+    ///
+    /// This sets [`Hook::state`] to [`State::MustReloadNode`] with `name`.
+    /// The node is despawned recursively, then respawned from the current
+    /// scene asset under the same parent, at the same index in its children,
+    /// and [`Hook::hook`] runs on the respawned entities. [`Hook::before`]
+    /// and [`Hook::on_loaded`] do not run.
+    ///
+    /// The node is looked up in the descendants of the scene entity, and in
+    /// the scene asset's entities. If several entities have the same name,
+    /// the first one found is reloaded.
+    ///
+    /// Note that the respawned entities are not part of the scene instance:
+    /// they are not listed in [`SceneSpawner::iter_instance_entities`].
+    /// The respawned node has a [`HookSpawned`] component instead, so that
+    /// it is still despawned on [`State::MustReload`] and [`State::MustDelete`].
+    pub fn reload_node(&mut self, name: impl Into<String>) {
+        self.set_state(State::MustReloadNode(Name::new(name.into())));
+    }
+
+    /// Set [`Hook::state`] to `new_state`, and run [`Hook::on_state_change`]
+    /// if it changed.
+    fn set_state(&mut self, new_state: State) {
+        let old_state = std::mem::replace(&mut self.state, new_state.clone());
+        self.asset_modified = false;
+        if new_state == State::Reloading {
+            self.reload_with = None;
+            self.generation += 1;
+        }
+        let Some(on_state_change) = &self.on_state_change else {
+            return;
+        };
+        if old_state != new_state {
+            on_state_change(old_state, new_state);
+        }
+    }
+
+    /// Keep the `C` component of scene entities across reloads.
+    ///
+    /// Before despawning the scene due to [`State::MustReload`], the `C`
+    /// components of scene entities are saved. They are inserted back on the
+    /// respawned entities after [`Hook::hook`] ran, overwriting the components
+    /// it added. This is useful to keep gameplay state, such as health, when
+    /// reloading a scene.
+    ///
+    /// Entities are matched by [`Name`]: entities without a `Name` are not
+    /// preserved, and if several entities have the same `Name`, only one of
+    /// them is preserved and restored on all of them.
+    ///
+    /// `C` must be registered in the [`AppTypeRegistry`] with `#[reflect(Component)]`,
+    /// otherwise it is not preserved and a warning is logged.
+    #[must_use]
+    pub fn retain<C: Component + Reflect>(mut self) -> Self {
+        self.retain.push(TypeId::of::<C>());
+        self
+    }
+
+    /// Save the components listed in [`Hook::retain`] of the scene `entities`.
+    fn save_retained(&self, world: &World, entities: impl Iterator<Item = Entity>) -> Retained {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let reflects: Vec<_> = self
+            .retain
+            .iter()
+            .filter_map(|type_id| {
+                let reflect = registry.get_type_data::<ReflectComponent>(*type_id);
+                if reflect.is_none() {
+                    bevy::log::warn!("Can't retain component {type_id:?}, it is not registered");
+                }
+                reflect
+            })
+            .collect();
+        let mut retained = Retained::default();
+        for entity in entities.filter_map(|e| world.get_entity(e)) {
+            let Some(name) = entity.get::<Name>() else {
+                continue;
+            };
+            let components = reflects
+                .iter()
+                .filter_map(|reflect| reflect.reflect(entity));
+            let components = components.map(Reflect::clone_value).collect();
+            retained.insert(name.to_string(), components);
+        }
+        retained
+    }
+
+    /// How many times the scene was reloaded, `0` before the first reload.
+    ///
+    /// This is incremented each time [`run_reloadable_hooks`] despawns the
+    /// scene for a reload, due to [`State::MustReload`], so it is already up to
+    /// date when [`Hook::hook`] runs on the respawned scene. Use
+    /// [`Hook::new_with_context`] to read it from the hook with [`Context::generation`].
+    #[must_use]
+    pub const fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Whether [`Hook::skip_unchanged_reloads`] should skip reloading `scene`.
+    fn is_unchanged(&self, world: &World, scene: &Handle<Scene>) -> bool {
+        if !self.skip_unchanged || !self.asset_modified || self.reload_with.is_some() {
+            return false;
+        }
+        let content = SceneContent::of(world, scene);
+        matches!((&self.content, content), (Some(old), Some(new)) if old.matches(&new))
+    }
+
+    fn run_root(callback: Option<&DynRootFn>, cmds: &mut Commands, world: &World, root: Entity) {
+        if let Some(callback) = callback {
+            callback(&mut cmds.entity(root), world);
         }
     }
 }
+/// Run `update` on the [`Hook`] of `root` when `cmds` are applied.
+fn update_hook(cmds: &mut Commands, root: Entity, update: impl FnOnce(&mut Hook) + Send + 'static) {
+    cmds.add(move |world: &mut World| {
+        if let Some(mut hook) = world.get_mut::<Hook>(root) {
+            update(&mut hook);
+        }
+    });
+}
+
 /// Command to update [`Hook`] in a [`Commands`] context.
 struct UpdateHook {
     entity: Entity,
     new_state: State,
+    /// Only update the state if it currently is this state.
+    only_from: Option<State>,
 }
 impl Command for UpdateHook {
     fn apply(self, world: &mut World) {
         if let Some(mut hook) = world.get_mut::<Hook>(self.entity) {
-            hook.state = self.new_state;
+            if self.only_from.is_some_and(|from| from != hook.state) {
+                return;
+            }
+            hook.set_state(self.new_state);
+        }
+    }
+}
+
+/// Extension trait on [`EntityCommands`] to reload or delete scenes with a [`Hook`].
+///
+/// This sets [`Hook::state`] when the commands are applied, use it when
+/// you don't have mutable access to the `Hook`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_scene_hook::reload::{self, ReloadSceneExt};
+///
+/// fn reload_all(scenes: Query<Entity, With<reload::Hook>>, mut cmds: Commands) {
+///     for scene in &scenes {
+///         cmds.entity(scene).reload_scene();
+///     }
+/// }
+/// ```
+pub trait ReloadSceneExt {
+    /// Set the scene's [`Hook::state`] to [`State::MustReload`].
+    fn reload_scene(&mut self) -> &mut Self;
+
+    /// Set the scene's [`Hook::state`] to [`State::MustReload`], only if
+    /// it is [`State::Hooked`], see [`Hook::request_reload`].
+    fn request_reload(&mut self) -> &mut Self;
+
+    /// Set the scene's [`Hook::state`] to [`State::MustDelete`].
+    fn delete_scene(&mut self) -> &mut Self;
+}
+impl ReloadSceneExt for EntityCommands<'_> {
+    fn reload_scene(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.commands().add(UpdateHook {
+            entity,
+            new_state: State::MustReload,
+            only_from: None,
+        });
+        self
+    }
+
+    fn request_reload(&mut self) -> &mut Self {
+        let entity = self.id();
+        let only_from = Some(State::Hooked);
+        self.commands()
+            .add(UpdateHook { entity, new_state: State::MustReload, only_from });
+        self
+    }
+
+    fn delete_scene(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.commands().add(UpdateHook {
+            entity,
+            new_state: State::MustDelete,
+            only_from: None,
+        });
+        self
+    }
+}
+
+/// The first descendant of `root` with the `name` [`Name`].
+fn find_named_descendant(world: &World, root: Entity, name: &str) -> Option<Entity> {
+    let mut to_visit = vec![root];
+    while let Some(entity) = to_visit.pop() {
+        if let Some(children) = world.get::<Children>(entity) {
+            to_visit.extend(children.iter().rev());
+        }
+        if entity != root
+            && world
+                .get::<Name>(entity)
+                .is_some_and(|n| n.as_str() == name)
+        {
+            return Some(entity);
+        }
+    }
+    None
+}
+
+/// Command to respawn a node of a scene, see [`Hook::reload_node`].
+struct ReloadNode {
+    root: Entity,
+    scene: Handle<Scene>,
+    name: String,
+    parent: Entity,
+    /// The index of the despawned node in the `Children` of `parent`.
+    index: usize,
+}
+impl ReloadNode {
+    /// Spawn the `name` node of `scene` and its descendants, returning the spawned entities.
+    fn spawn(&self, world: &mut World, scenes: &Assets<Scene>) -> Vec<Entity> {
+        let Some(scene) = scenes.get(&self.scene) else {
+            return Vec::new();
+        };
+        let source = &scene.world;
+        let is_node = |e: &EntityRef| e.get::<Name>().is_some_and(|n| n.as_str() == self.name);
+        let Some(node) = source.iter_entities().find(is_node) else {
+            bevy::log::warn!("Scene has no '{}' node to reload", self.name);
+            return Vec::new();
+        };
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let mut entity_map = EntityHashMap::default();
+        let mut to_visit = vec![node.id()];
+        while let Some(source_entity) = to_visit.pop() {
+            let entity = world.spawn_empty().id();
+            entity_map.insert(source_entity, entity);
+            let source_entity = source.entity(source_entity);
+            if let Some(children) = source_entity.get::<Children>() {
+                to_visit.extend(children.iter());
+            }
+            for component in source_entity.archetype().components() {
+                let info = source.components().get_info(component);
+                let reflect = info
+                    .and_then(ComponentInfo::type_id)
+                    .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id));
+                if let Some(reflect) = reflect {
+                    reflect.copy(source, world, source_entity.id(), entity, &registry);
+                }
+            }
+        }
+        for registration in registry.iter() {
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                map_entities.map_all_entities(world, &mut entity_map);
+            }
+        }
+        drop(registry);
+        let node = entity_map[&node.id()];
+        world
+            .entity_mut(node)
+            .remove::<Parent>()
+            .insert(HookSpawned(self.root));
+        let mut parent = world.entity_mut(self.parent);
+        let index = parent
+            .get::<Children>()
+            .map_or(0, |c| self.index.min(c.len()));
+        parent.insert_children(index, &[node]);
+        entity_map.into_values().collect()
+    }
+}
+impl Command for ReloadNode {
+    fn apply(self, world: &mut World) {
+        let spawned =
+            world.resource_scope(|world, scenes: Mut<Assets<Scene>>| self.spawn(world, &scenes));
+        let Some(reload) = world.get::<Hook>(self.root) else {
+            return;
+        };
+        let mut queue = CommandQueue::default();
+        let mut cmds = Commands::new(&mut queue, world);
+        for entity_ref in spawned.into_iter().filter_map(|e| world.get_entity(e)) {
+            if reload
+                .filter
+                .as_ref()
+                .is_some_and(|filter| !filter(&entity_ref))
+            {
+                continue;
+            }
+            let mut cmd = cmds.entity(entity_ref.id());
+            (reload.hook.0)(&entity_ref, &mut cmd, world, self.root);
+        }
+        queue.apply(world);
+    }
+}
+
+/// Command to insert back the components saved by [`Hook::retain`].
+struct RestoreRetained {
+    root: Entity,
+    entities: Vec<(Entity, String)>,
+}
+impl Command for RestoreRetained {
+    fn apply(self, world: &mut World) {
+        let Some(mut hook) = world.get_mut::<Hook>(self.root) else {
+            return;
+        };
+        let retained = std::mem::take(&mut hook.retained);
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        for (entity, name) in self.entities {
+            let (Some(components), Some(mut entity)) =
+                (retained.get(&name), world.get_entity_mut(entity))
+            else {
+                continue;
+            };
+            for component in components {
+                let type_id = component.get_represented_type_info().map(TypeInfo::type_id);
+                let reflect = type_id.and_then(|id| registry.get_type_data::<ReflectComponent>(id));
+                if let Some(reflect) = reflect {
+                    reflect.insert(&mut entity, &**component, &registry);
+                }
+            }
+        }
+    }
+}
+
+/// Command to save the content of a hooked scene, for [`Hook::skip_unchanged_reloads`].
+struct SaveContent {
+    root: Entity,
+    scene: Handle<Scene>,
+}
+impl Command for SaveContent {
+    fn apply(self, world: &mut World) {
+        let content = SceneContent::shared(world, &self.scene);
+        if let Some(mut hook) = world.get_mut::<Hook>(self.root) {
+            hook.content = content;
         }
     }
 }
 
 /// Run [`Hook`]s and respawn scenes according to [`Hook::state`].
+///
+/// This is added by [`Plugin`], but you can add it yourself to any schedule,
+/// as long as it runs after [`bevy::scene::scene_spawner_system`].
 pub fn run_reloadable_hooks(
     instances: Query<(Entity, &Handle<Scene>, &SceneInstance, &Hook)>,
     scene_manager: Res<SceneSpawner>,
     assets: Res<AssetServer>,
+    hook_spawned: Query<(Entity, &HookSpawned)>,
     world: &World,
     mut cmds: Commands,
 ) {
     for (entity, handle, instance, reload) in instances.iter() {
         let instance_ready = scene_manager.instance_is_ready(**instance);
-        match reload.state {
-            State::Loading if instance_ready => {
-                cmds.add(UpdateHook { entity, new_state: State::Hooked });
+        match &reload.state {
+            State::Loading | State::Reloading if instance_ready => {
+                cmds.add(UpdateHook { entity, new_state: State::Hooked, only_from: None });
+                if reload.skip_unchanged {
+                    cmds.add(SaveContent { root: entity, scene: handle.clone() });
+                }
+                let count = || scene_manager.iter_instance_entities(**instance).count();
+                let span = info_span!("run_reloadable_hooks", root = ?entity, entities = count());
+                let _span = span.entered();
+                Hook::run_root(reload.before.as_deref(), &mut cmds, world, entity);
                 let entities = scene_manager.iter_instance_entities(**instance);
                 for entity_ref in entities.filter_map(|e| world.get_entity(e)) {
-                    let mut cmd = cmds.entity(entity_ref.id());
+                    if reload
+                        .filter
+                        .as_ref()
+                        .is_some_and(|filter| !filter(&entity_ref))
+                    {
+                        continue;
+                    }
+                    let id = entity_ref.id();
+                    let _span = trace_span!("hook_entity", entity = ?id).entered();
+                    let mut cmd = cmds.entity(id);
                     (reload.hook.0)(&entity_ref, &mut cmd, world, entity);
                 }
+                if !reload.retained.is_empty() {
+                    let entities = scene_manager.iter_instance_entities(**instance);
+                    let named = |e| Some((e, world.get::<Name>(e)?.to_string()));
+                    let entities = entities.filter_map(named).collect();
+                    cmds.add(RestoreRetained { root: entity, entities });
+                }
+                Hook::run_root(reload.on_loaded.as_deref(), &mut cmds, world, entity);
+            }
+            State::Hooked | State::Loading | State::Reloading => {}
+            State::MustReload if reload.is_unchanged(world, handle) => {
+                cmds.add(UpdateHook { entity, new_state: State::Hooked, only_from: None });
             }
-            State::Hooked | State::Loading => continue,
             State::MustReload => {
-                let Some(file_path) = assets.get_path(handle) else {
-                    bevy::log::warn!("Tried to reload a scene without a registered path");
-                    continue;
+                let scene = match (&reload.reload_with, assets.get_path(handle)) {
+                    (Some(scene), _) => scene.clone(),
+                    (None, Some(file_path)) => assets.load::<Scene>(file_path),
+                    // Scenes added directly to `Assets<Scene>` have no path,
+                    // inserting back the same handle respawns them.
+                    (None, None) => handle.clone(),
                 };
+                Hook::run_root(reload.on_despawn.as_deref(), &mut cmds, world, entity);
+                if !reload.retain.is_empty() {
+                    let entities = scene_manager.iter_instance_entities(**instance);
+                    let retained = reload.save_retained(world, entities);
+                    update_hook(&mut cmds, entity, move |hook| hook.retained = retained);
+                }
                 let entities = scene_manager.iter_instance_entities(**instance);
+                let spawned = hook_spawned.iter().filter(|(_, s)| s.0 == entity);
+                let entities = entities.chain(spawned.map(|(e, _)| e));
                 for entity in entities.filter(|e| world.get_entity(*e).is_some()) {
                     cmds.entity(entity).despawn_recursive();
                 }
-                cmds.add(UpdateHook { entity, new_state: State::Loading });
-                cmds.entity(entity)
-                    .insert(assets.load::<Scene>(file_path))
-                    .remove::<SceneInstance>();
+                cmds.add(UpdateHook {
+                    entity,
+                    new_state: State::Reloading,
+                    only_from: None,
+                });
+                cmds.entity(entity).insert(scene).remove::<SceneInstance>();
+            }
+            State::MustReloadNode(name) => {
+                cmds.add(UpdateHook { entity, new_state: State::Hooked, only_from: None });
+                let node = find_named_descendant(world, entity, name);
+                let parent = node.and_then(|node| world.get::<Parent>(node));
+                let (Some(node), Some(parent)) = (node, parent) else {
+                    bevy::log::warn!("Tried to reload a '{name}' node not in the scene");
+                    continue;
+                };
+                let siblings = world.get::<Children>(parent.get());
+                let index = siblings.and_then(|s| s.iter().position(|e| *e == node));
+                cmds.entity(node).despawn_recursive();
+                cmds.add(ReloadNode {
+                    root: entity,
+                    scene: handle.clone(),
+                    name: name.to_string(),
+                    parent: parent.get(),
+                    index: index.unwrap_or(usize::MAX),
+                });
             }
             State::MustDelete => {
+                Hook::run_root(reload.on_delete.as_deref(), &mut cmds, world, entity);
+                Hook::run_root(reload.on_despawn.as_deref(), &mut cmds, world, entity);
                 let entities = scene_manager.iter_instance_entities(**instance);
+                let spawned = hook_spawned.iter().filter(|(_, s)| s.0 == entity);
+                let entities = entities.chain(spawned.map(|(e, _)| e));
                 for entity in entities.filter(|e| world.get_entity(*e).is_some()) {
                     cmds.entity(entity).despawn_recursive();
                 }
@@ -149,15 +899,149 @@ pub fn run_reloadable_hooks(
     }
 }
 
+/// Convenience parameter to query if a reloadable scene marked with `M` is hooked.
+///
+/// The reload counterpart of [`HookedSceneState`](crate::HookedSceneState).
+#[derive(SystemParam)]
+pub struct ReloadHookedState<'w, 's, M: Component> {
+    query: Query<'w, 's, &'static Hook, With<M>>,
+}
+impl<T: Component> ReloadHookedState<'_, '_, T> {
+    /// Whether any scene with `T` component is in the [`State::Hooked`] state.
+    ///
+    /// This is `false` while the scene is (re)loading.
+    #[must_use]
+    pub fn is_loaded(&self) -> bool {
+        self.query.iter().any(|hook| hook.state == State::Hooked)
+    }
+}
+
+/// Convenience run criteria to query if a reloadable scene marked with `M`
+/// is in the [`State::Hooked`] state.
+#[allow(clippy::must_use_candidate)]
+pub fn is_reload_hooked<M: Component>(state: ReloadHookedState<M>) -> bool {
+    state.is_loaded()
+}
+
+/// The latest [`SceneContent`] of each scene asset, shared by the [`Hook`]s
+/// hooked on it.
+#[derive(Resource, Default)]
+struct SceneContents(HashMap<AssetId<Scene>, Arc<SceneContent>>);
+
+/// The reflected components of a scene asset, for [`Hook::skip_unchanged_reloads`].
+///
+/// The components of each scene entity, cloned with [`Reflect::clone_value`].
+struct SceneContent(Vec<Vec<Box<dyn Reflect>>>);
+impl SceneContent {
+    /// The content of the `scene` asset.
+    ///
+    /// `None` if the scene asset is not loaded, or if it has components that
+    /// are not reflected, since they can't be compared.
+    fn of(world: &World, scene: &Handle<Scene>) -> Option<Self> {
+        let scene = world.resource::<Assets<Scene>>().get(scene)?;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let components = scene.world.components();
+        let entity_content = |entity: EntityRef| {
+            let component = |id| {
+                let type_id = components.get_info(id)?.type_id()?;
+                let reflect = registry.get_type_data::<ReflectComponent>(type_id)?;
+                Some(reflect.reflect(entity)?.clone_value())
+            };
+            entity.archetype().components().map(component).collect()
+        };
+        scene
+            .world
+            .iter_entities()
+            .map(entity_content)
+            .collect::<Option<_>>()
+            .map(Self)
+    }
+
+    /// The content of the `scene` asset, shared with the other [`Hook`]s of
+    /// `scene` if it didn't change since they were hooked.
+    fn shared(world: &mut World, scene: &Handle<Scene>) -> Option<Arc<Self>> {
+        let content = Self::of(world, scene)?;
+        let mut contents = world.get_resource_or_insert_with(SceneContents::default);
+        // Forget the content of scenes no `Hook` uses anymore.
+        contents
+            .0
+            .retain(|_, content| Arc::strong_count(content) > 1);
+        match contents.0.get(&scene.id()) {
+            Some(shared) if shared.matches(&content) => Some(Arc::clone(shared)),
+            _ => {
+                let content = Arc::new(content);
+                contents.0.insert(scene.id(), Arc::clone(&content));
+                Some(content)
+            }
+        }
+    }
+
+    /// Whether `other` has the same components with the same values.
+    ///
+    /// Values that can't be compared with [`Reflect::reflect_partial_eq`]
+    /// are considered different.
+    fn matches(&self, other: &Self) -> bool {
+        let mut values = self.0.iter().flatten().zip(other.0.iter().flatten());
+        self.0.iter().map(Vec::len).eq(other.0.iter().map(Vec::len))
+            && values.all(|(old, new)| old.reflect_partial_eq(new.as_ref()) == Some(true))
+    }
+}
+
+/// Set [`Hook::state`] to [`State::MustReload`] for hooked scenes which asset was modified.
+///
+/// Added by [`AutoReloadPlugin`].
+pub fn reload_modified_scenes(
+    mut events: EventReader<AssetEvent<Scene>>,
+    mut hooks: Query<(&Handle<Scene>, &mut Hook)>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        for (_, mut hook) in hooks.iter_mut().filter(|(handle, _)| handle.id() == *id) {
+            if hook.state == State::Hooked {
+                hook.set_state(State::MustReload);
+                hook.asset_modified = true;
+            }
+        }
+    }
+}
+
+/// Reload [`Hook`] scenes when their asset is modified, for example when
+/// hot reloading assets with the asset server's `file_watcher`.
+///
+/// This is opt-in, add it in addition to [`Plugin`]. Without it, scenes are
+/// only reloaded when you set [`Hook::state`] to [`State::MustReload`].
+///
+/// Like [`Plugin`], it runs in the schedule set with
+/// [`HookPlugin::in_schedule`](crate::HookPlugin::in_schedule) when added after
+/// [`HookPlugin`](crate::HookPlugin).
+pub struct AutoReloadPlugin;
+impl BevyPlugin for AutoReloadPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        let schedule = crate::hook_schedule(app);
+        app.add_systems(
+            schedule,
+            reload_modified_scenes.before(run_reloadable_hooks),
+        );
+    }
+}
+
 /// The plugin to manage reloading [`Hook`]s. It just registers [`Hook`],
 /// [`State`] and adds the [`run_reloadable_hooks`] system.
+///
+/// The system runs in [`SpawnScene`](bevy::prelude::SpawnScene), or in the
+/// schedule set with [`HookPlugin::in_schedule`](crate::HookPlugin::in_schedule)
+/// when added after [`HookPlugin`](crate::HookPlugin).
 pub struct Plugin;
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
+        let schedule = crate::hook_schedule(app);
         app.register_type::<Hook>()
             .register_type::<State>()
+            .register_type::<HookSpawned>()
             .add_systems(
-                bevy::prelude::SpawnScene,
+                schedule,
                 run_reloadable_hooks.after(bevy::scene::scene_spawner_system),
             );
     }
@@ -0,0 +1,64 @@
+//! Fixtures shared by the integration tests.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use bevy::{prelude::*, scene::ScenePlugin};
+
+/// An app able to spawn scenes, with `plugin` added.
+pub fn app(plugin: impl Plugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        HierarchyPlugin,
+        ScenePlugin,
+    ))
+    .add_plugins(plugin);
+    app
+}
+
+/// A scene with one entity per name in `names`.
+pub fn scene(names: &[&'static str]) -> Scene {
+    let mut world = World::new();
+    for name in names {
+        world.spawn(Name::new(*name));
+    }
+    Scene::new(world)
+}
+
+/// Add a [`scene`] with one entity per name in `names` to the `app` assets.
+pub fn add_scene(app: &mut App, names: &[&'static str]) -> Handle<Scene> {
+    app.world.resource_mut::<Assets<Scene>>().add(scene(names))
+}
+
+/// Update `app` enough times for the scenes it spawns to be hooked.
+pub fn settle(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+/// A count shared between hooks and the test reading it.
+#[derive(Clone, Default)]
+pub struct Counter(Arc<AtomicUsize>);
+impl Counter {
+    pub fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Update `app` until `done`, panicking if it takes more than a few seconds.
+pub fn update_until(app: &mut App, mut done: impl FnMut(&mut World) -> bool) {
+    for _ in 0..500 {
+        app.update();
+        if done(&mut app.world) {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    panic!("condition not reached after 500 updates");
+}
@@ -0,0 +1,863 @@
+//! Integration tests for [`SceneHook`] running on spawned scenes.
+use std::task::Poll;
+
+use bevy::ecs::schedule::ScheduleBuildSettings;
+use bevy::ecs::system::{EntityCommands, RunSystemOnce};
+use bevy::prelude::*;
+use bevy_scene_hook::{
+    run_hook_on_instance, scene_root, HookTemplates, HookedDynamicSceneBundle, HookedSceneBundle,
+};
+use bevy_scene_hook::{HookAppExt, HookStats, HookTotalEntities, HookedSceneState, SceneEntities};
+use bevy_scene_hook::{HookChildrenExt, HookContext, HookInserted, HookInstance, HookPlugin};
+use bevy_scene_hook::{HookProgress, HookTask, RehookRequest, SceneHook, SceneHooked, Systems};
+use bevy_scene_hook::{HookRoot, HookTimedOut, RemoveHookInsertedExt};
+use bevy_scene_hook::{SceneHookCompleted, SceneHookRejected, SceneVerdict};
+use bevy_scene_hook::{SceneId, SceneIdPlugin, SceneIds};
+
+mod common;
+use common::{add_scene, app, scene, settle, update_until, Counter};
+
+#[test]
+fn hook_waits_for_instance_ready() {
+    let mut app = app(HookPlugin);
+    let handle = app.world.resource::<Assets<Scene>>().reserve_handle();
+
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    });
+    let root = app
+        .world
+        .spawn(HookedSceneBundle {
+            hook,
+            scene: SceneBundle { scene: handle.clone(), ..default() },
+        })
+        .id();
+
+    settle(&mut app);
+    assert_eq!(hooked.get(), 0);
+    assert!(app.world.get::<SceneHooked>(root).is_none());
+
+    let scene = scene(&["Pile", "Card"]);
+    app.world
+        .resource_mut::<Assets<Scene>>()
+        .insert(&handle, scene);
+    app.update();
+    app.update();
+
+    // The two scene entities and the root.
+    assert_eq!(hooked.get(), 3);
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+}
+
+#[derive(Component)]
+struct Unload;
+
+fn unload(to_unload: Query<Entity, With<Unload>>, mut cmds: Commands) {
+    for entity in &to_unload {
+        cmds.entity(entity).despawn_recursive();
+    }
+}
+
+#[test]
+fn hook_skips_root_despawned_in_same_frame() {
+    let mut app = app(HookPlugin);
+    // Apply the `unload` despawn at the same time as the hook commands.
+    app.edit_schedule(SpawnScene, |schedule| {
+        schedule.set_build_settings(ScheduleBuildSettings {
+            auto_insert_apply_deferred: false,
+            ..default()
+        });
+    });
+    app.add_systems(SpawnScene, unload.before(Systems::SceneHookRunner));
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hook = SceneHook::new(|_, cmds| {
+        cmds.insert(Name::new("Hooked"));
+    });
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    app.update();
+    app.world.entity_mut(root).insert(Unload);
+    app.update();
+    app.update();
+
+    assert!(app.world.get_entity(root).is_none());
+    let mut hooked = app.world.query::<&SceneHooked>();
+    assert_eq!(hooked.iter(&app.world).count(), 0);
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Clutter;
+
+#[derive(Component)]
+struct WasCluttered;
+
+#[test]
+fn strip_removes_component_after_hooks_read_it() {
+    let mut app = app(HookPlugin);
+    app.register_type::<Clutter>();
+    let mut world = World::new();
+    world.spawn((Name::new("Lamp"), Clutter));
+    world.spawn(Name::new("Pile"));
+    let handle = app
+        .world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(world));
+    // The second hook still sees `Clutter`, since the removal is deferred.
+    let hook = SceneHook::strip::<Clutter>().and_then(SceneHook::new(|entity, cmds| {
+        if entity.contains::<Clutter>() {
+            cmds.insert(WasCluttered);
+        }
+    }));
+    app.world.spawn(HookedSceneBundle::new(handle, hook));
+
+    settle(&mut app);
+    let mut clutter = app.world.query::<&Clutter>();
+    assert_eq!(clutter.iter(&app.world).count(), 0);
+    let mut cluttered = app.world.query_filtered::<&Name, With<WasCluttered>>();
+    let names: Vec<_> = cluttered.iter(&app.world).map(Name::as_str).collect();
+    assert_eq!(names, ["Lamp"]);
+}
+
+#[derive(Resource)]
+struct LevelScene(Handle<Scene>);
+
+#[derive(Resource, Default)]
+struct HookedInUpdate(usize);
+
+fn spawn_level(mut cmds: Commands, level: Res<LevelScene>, mut spawned: Local<bool>) {
+    if !*spawned {
+        *spawned = true;
+        let hook = SceneHook::new(|_, cmds| {
+            cmds.insert(WasCluttered);
+        });
+        cmds.spawn(HookedSceneBundle::new(level.0.clone(), hook));
+    }
+}
+
+fn count_hooked(hooked: Query<(), With<WasCluttered>>, mut count: ResMut<HookedInUpdate>) {
+    count.0 = hooked.iter().count();
+}
+
+#[test]
+fn hook_in_pre_update_before_update() {
+    let mut app = app(HookPlugin.in_schedule(PreUpdate).spawn_scenes());
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    app.insert_resource(LevelScene(handle))
+        .init_resource::<HookedInUpdate>()
+        .add_systems(PreUpdate, spawn_level.before(bevy::scene::scene_spawner))
+        .add_systems(Update, count_hooked);
+
+    app.update();
+
+    // The two scene entities and the root, hooked in the frame they were spawned.
+    assert_eq!(app.world.resource::<HookedInUpdate>().0, 3);
+}
+
+#[derive(Component)]
+struct Level;
+
+fn spawn_marked_level(mut cmds: Commands, level: Res<LevelScene>, mut spawned: Local<bool>) {
+    if !*spawned {
+        *spawned = true;
+        cmds.spawn((SceneBundle { scene: level.0.clone(), ..default() }, Level));
+    }
+}
+
+#[test]
+fn marker_hook_in_pre_update_before_update() {
+    let mut app = app(HookPlugin.in_schedule(PreUpdate).spawn_scenes());
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hook = SceneHook::new(|_, cmds| {
+        cmds.insert(WasCluttered);
+    });
+    app.insert_resource(LevelScene(handle))
+        .init_resource::<HookedInUpdate>()
+        .add_scene_hook::<Level>(hook)
+        .add_systems(
+            PreUpdate,
+            spawn_marked_level.before(bevy::scene::scene_spawner),
+        )
+        .add_systems(Update, count_hooked);
+
+    app.update();
+
+    // The two scene entities and the root, hooked in the frame they were spawned.
+    assert_eq!(app.world.resource::<HookedInUpdate>().0, 3);
+}
+
+#[derive(Resource, Default)]
+struct LevelState {
+    loaded: bool,
+    completed: usize,
+}
+
+fn track_level(
+    state: HookedSceneState<Level>,
+    mut completed: EventReader<SceneHookCompleted>,
+    mut level: ResMut<LevelState>,
+) {
+    level.loaded = state.is_loaded();
+    level.completed += completed.read().count();
+}
+
+#[test]
+fn marker_hooked_scene_is_hooked_once() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let own_hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    });
+    let marker_hook = SceneHook::new(|_, cmds| {
+        cmds.insert(WasCluttered);
+    });
+    app.init_resource::<LevelState>()
+        .add_scene_hook::<Level>(marker_hook)
+        .add_systems(Last, track_level);
+    let root = app
+        .world
+        .spawn((HookedSceneBundle::new(handle, own_hook), Level))
+        .id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+    // Both hooks ran on the two scene entities and the root.
+    assert_eq!(hooked.get(), 3);
+    let mut cluttered = app.world.query_filtered::<(), With<WasCluttered>>();
+    assert_eq!(cluttered.iter(&app.world).count(), 3);
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+    let level = app.world.resource::<LevelState>();
+    assert!(level.loaded);
+    assert_eq!(level.completed, 1);
+}
+
+#[derive(Component)]
+struct Pickup(usize);
+
+#[test]
+fn mut_hook_state_is_per_instance() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pickup", "Pickup"]);
+    let hook = SceneHook::new_mut(|| {
+        let mut pickup_count = 0;
+        move |entity: &EntityRef, cmds: &mut EntityCommands| {
+            if entity.get::<Name>().map(Name::as_str) == Some("Pickup") {
+                cmds.insert(Pickup(pickup_count));
+                pickup_count += 1;
+            }
+        }
+    });
+    app.add_scene_hook::<Level>(hook);
+    for _ in 0..2 {
+        let scene = SceneBundle { scene: handle.clone(), ..default() };
+        app.world.spawn((scene, Level));
+    }
+
+    settle(&mut app);
+    let mut pickups = app.world.query::<&Pickup>();
+    let mut pickups: Vec<_> = pickups.iter(&app.world).map(|p| p.0).collect();
+    pickups.sort_unstable();
+    assert_eq!(pickups, [0, 0, 1, 1]);
+}
+
+#[test]
+fn mut_hook_state_is_fresh_on_rehook_without_root_visit() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pickup", "Pickup"]);
+    let hook = SceneHook::new_mut(|| {
+        let mut pickup_count = 0;
+        move |_: &EntityRef, cmds: &mut EntityCommands| {
+            cmds.insert(Pickup(pickup_count));
+            pickup_count += 1;
+        }
+    });
+    // The scene entity has no `Name`, so the hook never runs on it.
+    let hook = hook.with_component::<Name>().rehookable();
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    app.world.entity_mut(root).insert(RehookRequest);
+    settle(&mut app);
+
+    let mut pickups = app.world.query::<&Pickup>();
+    let mut pickups: Vec<_> = pickups.iter(&app.world).map(|p| p.0).collect();
+    pickups.sort_unstable();
+    assert_eq!(pickups, [0, 1]);
+}
+
+#[derive(Component)]
+struct Loaded(&'static str);
+
+#[test]
+fn marker_hook_on_loaded_runs_with_scene_hook() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let marker_hook = SceneHook::new(|_, _| {}).on_loaded(|cmds, _| {
+        cmds.insert(Loaded("marker"));
+        SceneVerdict::Keep
+    });
+    app.add_scene_hook::<Level>(marker_hook);
+    let own_hook = SceneHook::new(|_, cmds| {
+        cmds.insert(WasCluttered);
+    });
+    let root = app
+        .world
+        .spawn((HookedSceneBundle::new(handle, own_hook), Level))
+        .id();
+    settle(&mut app);
+
+    let loaded = app.world.get::<Loaded>(root).map(|loaded| loaded.0);
+    assert_eq!(loaded, Some("marker"));
+    let mut cluttered = app.world.query_filtered::<(), With<WasCluttered>>();
+    assert_eq!(cluttered.iter(&app.world).count(), 2);
+}
+
+#[test]
+fn scene_ids_assigned_before_hooks_in_custom_schedule() {
+    let mut app = app(HookPlugin.in_schedule(PreUpdate).spawn_scenes());
+    app.add_plugins(SceneIdPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let identified = Counter::default();
+    let counter = identified.clone();
+    let hook = SceneHook::new_with_world(move |_, _, world, root| {
+        if world.get::<SceneId>(root).is_some() {
+            counter.increment();
+        }
+    });
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    app.update();
+
+    // The hook saw the id on the two scene entities and the root.
+    assert_eq!(identified.get(), 3);
+    let id = *app.world.get::<SceneId>(root).unwrap();
+    assert_eq!(app.world.resource::<SceneIds>().get(id), Some(root));
+}
+
+#[derive(Component)]
+struct Leaf;
+
+/// Hook `name` entities of the scene by spawning `inner` hooked with `hook()` in them.
+fn nest(
+    name: &'static str,
+    inner: Handle<Scene>,
+    hook: impl Fn() -> SceneHook + Send + Sync + 'static,
+) -> SceneHook {
+    SceneHook::new(move |entity, cmds| {
+        if entity.get::<Name>().map(Name::as_str) == Some(name) {
+            cmds.insert(HookedSceneBundle::new(inner.clone(), hook()));
+        }
+    })
+}
+
+#[test]
+fn hook_nested_scenes() {
+    let mut app = app(HookPlugin);
+    let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+    let leaf = scenes.add(scene(&["Card"]));
+    let middle = scenes.add(scene(&["Deck"]));
+    let outer = scenes.add(scene(&["Table"]));
+
+    let leaf_hook = || {
+        SceneHook::new(|entity, cmds| {
+            if entity.get::<Name>().map(Name::as_str) == Some("Card") {
+                cmds.insert(Leaf);
+            }
+        })
+    };
+    let middle_hook = move || nest("Deck", leaf.clone(), leaf_hook);
+    let outer_hook = nest("Table", middle, middle_hook);
+    let outer_root = app
+        .world
+        .spawn(HookedSceneBundle::new(outer, outer_hook))
+        .id();
+
+    for _ in 0..6 {
+        app.update();
+    }
+    let mut leaves = app.world.query_filtered::<&Name, With<Leaf>>();
+    let names: Vec<_> = leaves.iter(&app.world).map(Name::as_str).collect();
+    assert_eq!(names, ["Card"]);
+    // The outer root and the two nested scene entities.
+    let mut hooked = app.world.query_filtered::<Entity, With<SceneHooked>>();
+    assert_eq!(hooked.iter(&app.world).count(), 3);
+    assert!(app.world.get::<SceneHooked>(outer_root).is_some());
+}
+
+#[derive(Component)]
+struct Card;
+
+#[test]
+fn hook_scene_loaded_from_file() {
+    let mut app = app(HookPlugin);
+    let handle = app.world.resource::<AssetServer>().load("cards.scn.ron");
+    let hook = SceneHook::named("Card", |cmds| {
+        cmds.insert(Card);
+    });
+    let root = app
+        .world
+        .spawn(HookedDynamicSceneBundle::new(handle, hook))
+        .id();
+
+    update_until(&mut app, |world| world.get::<SceneHooked>(root).is_some());
+
+    let mut cards = app.world.query_filtered::<&Name, With<Card>>();
+    let names: Vec<_> = cards.iter(&app.world).map(Name::as_str).collect();
+    assert_eq!(names, ["Card"]);
+    let mut scene_entities = app.world.query_filtered::<&Name, With<HookRoot>>();
+    assert_eq!(scene_entities.iter(&app.world).count(), 2);
+}
+
+#[derive(Component)]
+struct Slots(Vec<String>);
+
+#[test]
+fn hook_reads_children_of_entity() {
+    let mut app = app(HookPlugin);
+    let mut world = World::new();
+    world.spawn(Name::new("Rig")).with_children(|rig| {
+        rig.spawn(Name::new("LeftHand"));
+        rig.spawn(Name::new("RightHand"));
+    });
+    let handle = app
+        .world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(world));
+    let hook = SceneHook::new_with_world(|entity, cmds, world, _| {
+        if entity.get::<Name>().map(Name::as_str) == Some("Rig") {
+            let names = entity.children().iter().map(|c| world.get::<Name>(*c));
+            let names = names.map(|name| name.unwrap().to_string()).collect();
+            cmds.insert(Slots(names));
+        }
+    });
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    settle(&mut app);
+    let mut slots = app.world.query::<&Slots>();
+    let slots: Vec<_> = slots.iter(&app.world).map(|s| s.0.as_slice()).collect();
+    assert_eq!(slots, [["LeftHand", "RightHand"]]);
+    assert_eq!(app.world.entity(root).children().len(), 1);
+}
+
+#[test]
+fn shallow_budgeted_progress_reaches_total() {
+    let mut app = app(HookPlugin);
+    let mut world = World::new();
+    world.spawn(Name::new("Rig")).with_children(|rig| {
+        rig.spawn(Name::new("LeftHand"));
+        rig.spawn(Name::new("RightHand"));
+    });
+    world.spawn(Name::new("Lamp"));
+    let handle = app
+        .world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(world));
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    });
+    let hook = hook.shallow().budgeted(2);
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    let mut progress = Vec::new();
+    for _ in 0..6 {
+        app.update();
+        let root = app.world.entity(root);
+        if let (Some(next), Some(total)) =
+            (root.get::<HookProgress>(), root.get::<HookTotalEntities>())
+        {
+            let HookTotalEntities(total) = *total;
+            progress.push((next.next, total));
+        }
+    }
+    assert_eq!(progress, [(2, 4), (4, 4)]);
+    // "Rig", "Lamp" and the root, but not the hands.
+    assert_eq!(hooked.get(), 3);
+    let stats = app.world.get::<HookStats>(root);
+    assert_eq!(stats.map(|stats| stats.total), Some(4));
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+}
+
+#[derive(Component, Debug, PartialEq)]
+struct Context {
+    parent: Option<Entity>,
+    depth: u32,
+}
+
+#[test]
+fn context_of_scene_spawned_under_parent() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = SceneHook::new_with_context(|_, cmds, ctx| {
+        let HookContext { parent, depth, .. } = *ctx;
+        cmds.insert(Context { parent, depth });
+    });
+    let level = app.world.spawn(SpatialBundle::default()).id();
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+    app.world.entity_mut(level).add_child(root);
+
+    settle(&mut app);
+    let expected = Context { parent: None, depth: 0 };
+    assert_eq!(app.world.get::<Context>(root), Some(&expected));
+    let pile = app.world.entity(root).children()[0];
+    let expected = Context { parent: Some(root), depth: 1 };
+    assert_eq!(app.world.get::<Context>(pile), Some(&expected));
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Lit;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Relit;
+
+fn inserted(app: &App, entity: Entity) -> Vec<&str> {
+    let inserted = app.world.get::<HookInserted>(entity).unwrap().components();
+    let names = inserted.iter().map(|c| app.world.components().get_name(*c));
+    names.map(Option::unwrap).collect()
+}
+
+#[test]
+fn track_inserted_ignores_bookkeeping_and_keeps_rehook_record() {
+    let mut app = app(HookPlugin);
+    app.register_type::<Lit>().register_type::<Relit>();
+    let handle = add_scene(&mut app, &["Lamp"]);
+    let hook = SceneHook::new(|entity, cmds| {
+        if entity.contains::<Lit>() {
+            cmds.insert(Relit);
+        } else {
+            cmds.insert(Lit);
+        }
+    });
+    let hook = hook.with_root_name("Lamps").track_inserted().rehookable();
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    assert_eq!(inserted(&app, root).len(), 1);
+    assert!(inserted(&app, root)[0].ends_with("::Lit"));
+
+    app.world.entity_mut(root).insert(RehookRequest);
+    settle(&mut app);
+    assert_eq!(inserted(&app, root).len(), 2);
+
+    app.world.run_system_once(move |mut cmds: Commands| {
+        cmds.entity(root).remove_hook_inserted();
+    });
+    let root = app.world.entity(root);
+    assert!(!root.contains::<Lit>() && !root.contains::<Relit>());
+    assert!(root.contains::<HookStats>() && root.contains::<HookInstance>());
+    assert!(root.contains::<Name>());
+}
+
+#[test]
+fn rehook_resumes_from_cursor() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card", "Deck"]);
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    });
+    let root = app
+        .world
+        .spawn(HookedSceneBundle::new(handle, hook.rehookable()))
+        .id();
+
+    settle(&mut app);
+    // The three scene entities and the root.
+    assert_eq!(hooked.get(), 4);
+
+    app.world
+        .entity_mut(root)
+        .insert((RehookRequest, HookProgress { next: 2 }));
+    settle(&mut app);
+    // Only the last scene entity and the root are hooked again.
+    assert_eq!(hooked.get(), 6);
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+    assert!(app.world.get::<HookProgress>(root).is_none());
+}
+
+#[derive(Component)]
+struct IsRoot;
+
+#[test]
+fn cursor_on_unhooked_scene_runs_root_hook() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card", "Deck"]);
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    })
+    .root(|cmds, _| {
+        cmds.insert(IsRoot);
+    });
+    let root = app
+        .world
+        .spawn((
+            HookedSceneBundle::new(handle, hook),
+            HookProgress { next: 2 },
+        ))
+        .id();
+
+    settle(&mut app);
+    // Only the last scene entity and the root are hooked.
+    assert_eq!(hooked.get(), 2);
+    assert!(app.world.get::<IsRoot>(root).is_some());
+    assert!(app.world.get::<HookStats>(root).is_some());
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Templated;
+
+#[test]
+fn templated_instances_have_their_own_root() {
+    let mut app = app(HookPlugin);
+    app.register_type::<Templated>();
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hook = || {
+        SceneHook::new(|_, cmds| {
+            cmds.insert(Templated);
+        })
+        .templated()
+    };
+    let first = app
+        .world
+        .spawn(HookedSceneBundle::new(handle.clone(), hook()))
+        .id();
+    settle(&mut app);
+    let second = app
+        .world
+        .spawn(HookedSceneBundle::new(handle.clone(), hook()))
+        .id();
+    settle(&mut app);
+    // The second instance used the template recorded by the first one.
+    assert!(app.world.resource::<HookTemplates>().contains(handle.id()));
+
+    for root in [first, second] {
+        let children = app.world.get::<Children>(root).unwrap().to_vec();
+        assert_eq!(children.len(), 2);
+        for child in children {
+            assert!(app.world.get::<Templated>(child).is_some());
+            assert_eq!(scene_root(&app.world, child), Some(root));
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct TasksDone(bool);
+
+struct WaitForDone;
+impl HookTask for WaitForDone {
+    fn poll(&mut self, _: &mut EntityCommands, world: &World) -> Poll<()> {
+        if world.resource::<TasksDone>().0 {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+fn completed_count(app: &App) -> usize {
+    let events = app.world.resource::<Events<SceneHookCompleted>>();
+    events.get_reader().read(events).count()
+}
+
+#[test]
+fn deferred_hook_completes_with_its_tasks() {
+    let mut app = app(HookPlugin);
+    app.init_resource::<TasksDone>();
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = SceneHook::new_deferred(|_, _| Some(Box::new(WaitForDone) as Box<dyn HookTask>));
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(completed_count(&app), 0);
+    }
+    assert!(app.world.get::<SceneHooked>(root).is_none());
+
+    app.world.resource_mut::<TasksDone>().0 = true;
+    app.update();
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+    assert_eq!(completed_count(&app), 1);
+}
+
+#[derive(Resource, Default)]
+struct SystemInput(Option<SceneEntities>);
+
+fn record_input(In(scene): In<SceneEntities>, mut input: ResMut<SystemInput>) {
+    input.0 = Some(scene);
+}
+
+#[test]
+fn hook_system_entities_exclude_root() {
+    let mut app = app(HookPlugin);
+    app.init_resource::<SystemInput>();
+    let system = app.world.register_system(record_input);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hook = SceneHook::from_system(system);
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    settle(&mut app);
+    let input = app.world.resource::<SystemInput>().0.clone().unwrap();
+    assert_eq!(input.root, root);
+    assert_eq!(input.entities.len(), 2);
+    assert!(!input.entities.contains(&root));
+}
+
+#[derive(Resource, Default)]
+struct Outcomes {
+    completed: usize,
+    rejected: usize,
+}
+
+fn track_outcomes(
+    mut completed: EventReader<SceneHookCompleted>,
+    mut rejected: EventReader<SceneHookRejected>,
+    mut outcomes: ResMut<Outcomes>,
+) {
+    outcomes.completed += completed.read().count();
+    outcomes.rejected += rejected.read().count();
+}
+
+#[test]
+fn rejected_exclusive_hook_does_not_complete() {
+    let mut app = app(HookPlugin);
+    app.init_resource::<SystemInput>()
+        .init_resource::<Outcomes>()
+        .add_systems(Last, track_outcomes);
+    let system = app.world.register_system(record_input);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let hook = SceneHook::from_system(system)
+        .on_loaded(|_, _| SceneVerdict::Despawn("No level here".to_owned()));
+    let root = app.world.spawn(HookedSceneBundle::new(handle, hook)).id();
+
+    settle(&mut app);
+    assert!(app.world.resource::<SystemInput>().0.is_some());
+    assert!(app.world.get_entity(root).is_none());
+    let outcomes = app.world.resource::<Outcomes>();
+    assert_eq!(outcomes.rejected, 1);
+    assert_eq!(outcomes.completed, 0);
+}
+
+#[test]
+fn hook_on_instance_without_spawner_returns() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let instance = app.world.resource_mut::<SceneSpawner>().spawn(handle);
+    settle(&mut app);
+    app.world.remove_resource::<SceneSpawner>();
+
+    let root = app.world.spawn_empty().id();
+    let hook = SceneHook::new_with_resource::<Time, _>(|_, _, _, _| {});
+    assert!(!run_hook_on_instance(instance, root, &hook, &mut app.world));
+    assert!(!run_hook_on_instance(
+        instance,
+        root,
+        &SceneHook::new(|_, _| {}),
+        &mut app.world
+    ));
+    assert!(app.world.get::<SceneHooked>(root).is_none());
+}
+
+#[test]
+fn unmarked_instance_hook_runs_once() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let instance = app.world.resource_mut::<SceneSpawner>().spawn(handle);
+    let hooked = Counter::default();
+    let counter = hooked.clone();
+    let hook = SceneHook::new(move |_, _| {
+        counter.increment();
+    });
+    let root = app
+        .world
+        .spawn((hook.no_marker(), HookInstance(instance)))
+        .id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+    // The two scene entities and the root, only once.
+    assert_eq!(hooked.get(), 3);
+    assert!(app.world.get::<SceneHooked>(root).is_none());
+
+    let registry = app.world.resource::<AppTypeRegistry>().read();
+    let reflect =
+        registry.get_type_data::<ReflectComponent>(std::any::TypeId::of::<HookInstance>());
+    let reflected = reflect.and_then(|reflect| reflect.reflect(app.world.entity(root)));
+    let expected = HookInstance(instance);
+    assert!(
+        reflected.is_some_and(|reflected| reflected.reflect_partial_eq(&expected) == Some(true))
+    );
+}
+
+#[test]
+fn ready_unmarked_instance_does_not_time_out() {
+    let mut app = app(HookPlugin);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let instance = app.world.resource_mut::<SceneSpawner>().spawn(handle);
+    settle(&mut app);
+    assert!(app
+        .world
+        .resource::<SceneSpawner>()
+        .instance_is_ready(instance));
+
+    let hook = SceneHook::new(|_, _| {}).no_marker();
+    let hook = hook.with_timeout(std::time::Duration::ZERO);
+    let root = app.world.spawn((hook, HookInstance(instance))).id();
+    settle(&mut app);
+
+    assert!(app.world.get::<HookTimedOut>(root).is_none());
+}
+
+#[cfg(feature = "gltf")]
+#[test]
+fn gltf_hook_and_scene_hook_on_same_entity() {
+    use bevy::gltf::Gltf;
+    use bevy_scene_hook::gltf::{GltfHook, GltfHooked};
+
+    let mut app = app(HookPlugin);
+    app.init_asset::<Gltf>();
+    let scene = add_scene(&mut app, &["Card"]);
+    let gltf = Gltf {
+        scenes: vec![scene.clone()],
+        named_scenes: default(),
+        meshes: Vec::new(),
+        named_meshes: default(),
+        materials: Vec::new(),
+        named_materials: default(),
+        nodes: Vec::new(),
+        named_nodes: default(),
+        default_scene: Some(scene),
+        animations: Vec::new(),
+        named_animations: default(),
+        source: None,
+    };
+    let gltf = app.world.resource_mut::<Assets<Gltf>>().add(gltf);
+    let gltf_hook = GltfHook::new(|gltf, cmds, _| {
+        if let Some(scene) = gltf.default_scene.clone() {
+            cmds.insert(SceneBundle { scene, ..default() });
+        }
+    });
+    let scene_hook = SceneHook::named("Card", |cmds| {
+        cmds.insert(Card);
+    });
+    let root = app.world.spawn((gltf, gltf_hook, scene_hook)).id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(app.world.get::<GltfHooked>(root).is_some());
+    assert!(app.world.get::<SceneHooked>(root).is_some());
+    let mut cards = app.world.query_filtered::<(), With<Card>>();
+    assert_eq!(cards.iter(&app.world).count(), 1);
+}
@@ -0,0 +1,650 @@
+//! Integration tests for [`reload::Hook`] reloading spawned scenes.
+#![cfg(feature = "reload")]
+use bevy::asset::{io::Reader, ron, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::ecs::system::{EntityCommands, RunSystemOnce};
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::utils::BoxedFuture;
+use bevy_scene_hook::{reload, SceneId, SceneIdPlugin, SceneIds};
+
+mod common;
+use common::{add_scene, app, scene, settle, update_until, Counter};
+
+#[derive(Component)]
+struct Extra;
+
+fn extra_count(app: &mut App) -> usize {
+    let mut extras = app.world.query_filtered::<(), With<Extra>>();
+    extras.iter(&app.world).count()
+}
+
+#[test]
+fn reload_despawns_hook_spawned_entities() {
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new_with_context(|_, cmds, ctx| {
+        // A child of the scene entity, and an entity outside of the scene.
+        cmds.with_children(|children| {
+            children.spawn(Extra);
+        });
+        cmds.commands()
+            .spawn((Extra, reload::HookSpawned(ctx.root)));
+    });
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+
+    settle(&mut app);
+    assert_eq!(extra_count(&mut app), 2);
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(hook.state, reload::State::Hooked);
+    assert_eq!(hook.generation(), 1);
+    // Only the entities spawned by the hook after the reload are left.
+    assert_eq!(extra_count(&mut app), 2);
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustDelete;
+    app.update();
+    assert_eq!(extra_count(&mut app), 0);
+}
+
+#[derive(Component)]
+struct Level(u32);
+
+#[test]
+fn reload_keeps_root_components() {
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let transform = Transform::from_xyz(1., 2., 3.);
+    let bundle = reload::SceneBundle::new(handle.clone(), hook).with_transform(transform);
+    let root = app.world.spawn((bundle, Level(4))).id();
+    app.world.entity_mut(root).insert(Visibility::Hidden);
+
+    settle(&mut app);
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let root = app.world.entity(root);
+    assert_eq!(
+        root.get::<reload::Hook>().unwrap().state,
+        reload::State::Hooked
+    );
+    assert_eq!(root.get::<Transform>(), Some(&transform));
+    assert_eq!(root.get::<Visibility>(), Some(&Visibility::Hidden));
+    assert_eq!(root.get::<Level>().map(|level| level.0), Some(4));
+    assert_eq!(root.get::<Handle<Scene>>(), Some(&handle));
+    assert_eq!(root.get::<Children>().map(|c| c.len()), Some(1));
+}
+
+#[derive(Component)]
+struct Card;
+
+fn card(app: &mut App) -> Option<Entity> {
+    let mut cards = app.world.query_filtered::<Entity, With<Card>>();
+    cards.get_single(&app.world).ok()
+}
+
+/// Load `.scn.ron` files as [`Scene`]s, bevy only loads them as [`DynamicScene`]s.
+struct SceneFileLoader(AppTypeRegistry);
+impl FromWorld for SceneFileLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.resource::<AppTypeRegistry>().clone())
+    }
+}
+impl AssetLoader for SceneFileLoader {
+    type Asset = Scene;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _: &'a (),
+        _: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Scene, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let type_registry = &self.0.read();
+            let scene = SceneDeserializer { type_registry };
+            let scene = ron::Options::default().from_bytes_seed(&bytes, scene)?;
+            Ok(Scene::from_dynamic_scene(&scene, &self.0)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.ron"]
+    }
+}
+
+#[test]
+fn reload_reapplies_hook_to_scene_loaded_from_file() {
+    let mut app = app(reload::Plugin);
+    app.init_asset_loader::<SceneFileLoader>();
+    let scene: Handle<Scene> = app.world.resource::<AssetServer>().load("cards.scn.ron");
+    update_until(&mut app, |world| {
+        world.resource::<Assets<Scene>>().contains(&scene)
+    });
+
+    let hook = reload::Hook::new(|entity, cmds, _, _| {
+        if entity.get::<Name>().map(Name::as_str) == Some("Card") {
+            cmds.insert(Card);
+        }
+    });
+    let root = app.world.spawn(reload::SceneBundle::new(scene, hook)).id();
+    settle(&mut app);
+    let first_card = card(&mut app).expect("the hook inserts Card");
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let reloaded_card = card(&mut app).expect("the hook inserts Card again");
+    assert_ne!(first_card, reloaded_card);
+    assert!(app.world.get_entity(first_card).is_none());
+}
+
+#[test]
+fn reload_scene_with_weak_handle() {
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Card"]);
+    let hook = reload::Hook::new(|entity, cmds, _, _| {
+        if entity.get::<Name>().map(Name::as_str) == Some("Card") {
+            cmds.insert(Card);
+        }
+    });
+    // A weak handle has no path, like scenes from an asset pack or built at runtime.
+    let bundle = reload::SceneBundle::new(handle.clone_weak(), hook);
+    let root = app.world.spawn(bundle).id();
+    settle(&mut app);
+    let first_card = card(&mut app).expect("the hook inserts Card");
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(hook.state, reload::State::Hooked);
+    assert_eq!(hook.generation(), 1);
+    let reloaded_card = card(&mut app).expect("the hook inserts Card again");
+    assert_ne!(first_card, reloaded_card);
+    assert_eq!(app.world.get::<Handle<Scene>>(root), Some(&handle));
+}
+
+#[derive(Component)]
+struct Label(String);
+
+fn labels(app: &App, root: Entity) -> Vec<String> {
+    let children = app.world.get::<Children>(root).unwrap();
+    let labels = children.iter().filter_map(|e| app.world.get::<Label>(*e));
+    labels.map(|label| label.0.clone()).collect()
+}
+
+#[test]
+fn reload_hooks_entities_in_stable_order() {
+    let mut app = app(reload::Plugin);
+    let names = [
+        "Heart", "Club", "Gambit", "Ace", "Flush", "Deck", "Eight", "Bishop",
+    ];
+    let handle = add_scene(&mut app, &names);
+    let hook = reload::Hook::new(|entity, cmds, _, root| {
+        let Some(name) = entity.get::<Name>() else {
+            return;
+        };
+        let label = (Label(name.to_string()), reload::HookSpawned(root));
+        cmds.commands().entity(root).with_children(|children| {
+            children.spawn(label);
+        });
+    });
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    let first_load = labels(&app, root);
+    assert_eq!(first_load.len(), names.len());
+
+    for generation in 1..4 {
+        app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+        settle(&mut app);
+        assert_eq!(
+            app.world.get::<reload::Hook>(root).unwrap().generation(),
+            generation
+        );
+        assert_eq!(labels(&app, root), first_load);
+    }
+}
+
+fn child_names(app: &App, entity: Entity) -> Vec<String> {
+    let children = app.world.get::<Children>(entity).unwrap();
+    let names = children.iter().filter_map(|e| app.world.get::<Name>(*e));
+    names.map(Name::to_string).collect()
+}
+
+#[test]
+fn reload_node_keeps_rest_of_scene() {
+    let mut app = app(reload::Plugin);
+    let mut world = World::new();
+    world.spawn(Name::new("Lamp"));
+    world.spawn(Name::new("Rig")).with_children(|rig| {
+        rig.spawn(Name::new("LeftHand"));
+        rig.spawn(Name::new("RightHand"));
+    });
+    world.spawn(Name::new("Table"));
+    let handle = app
+        .world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(world));
+    let hook = reload::Hook::new(|_, cmds, _, _| {
+        cmds.insert(Extra);
+    });
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    let order = child_names(&app, root);
+    let before: Vec<_> = app.world.get::<Children>(root).unwrap().to_vec();
+    assert_eq!(extra_count(&mut app), 5);
+
+    let rig = Name::new("Rig");
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReloadNode(rig);
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(hook.state, reload::State::Hooked);
+    assert_eq!(child_names(&app, root), order);
+    let after = app.world.get::<Children>(root).unwrap();
+    for (before, after) in before.iter().zip(after.iter()) {
+        let name = app.world.get::<Name>(*after).unwrap().as_str();
+        // Only the "Rig" node is respawned.
+        assert_eq!(before == after, name != "Rig", "{name}");
+        if name == "Rig" {
+            assert_eq!(child_names(&app, *after), ["LeftHand", "RightHand"]);
+        }
+    }
+    // The respawned "Rig" and hands are hooked again.
+    assert_eq!(extra_count(&mut app), 5);
+}
+
+#[test]
+fn reload_after_reload_node_despawns_respawned_node() {
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Lamp", "Rig", "Table"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    app.world
+        .get_mut::<reload::Hook>(root)
+        .unwrap()
+        .reload_node("Rig");
+    settle(&mut app);
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+
+    let children = app.world.get::<Children>(root).map(|c| c.len());
+    assert_eq!(children, Some(3));
+    let mut names = app.world.query::<&Name>();
+    assert_eq!(names.iter(&app.world).count(), 3);
+}
+
+#[test]
+fn skip_reload_of_unchanged_scene() {
+    let mut app = app(reload::Plugin);
+    app.add_plugins(reload::AutoReloadPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hooked = Counter::default();
+    let new_hook = || {
+        let counter = hooked.clone();
+        reload::Hook::new(move |_, _, _, _| counter.increment()).skip_unchanged_reloads()
+    };
+    let root = app
+        .world
+        .spawn(reload::SceneBundle::new(handle.clone(), new_hook()))
+        .id();
+    let other = app
+        .world
+        .spawn(reload::SceneBundle::new(handle, new_hook()))
+        .id();
+    settle(&mut app);
+    assert_eq!(hooked.get(), 2);
+
+    // The asset is replaced by an identical scene, as when touching the file.
+    let touched = reload_edited(&mut app, root, scene(&["Pile"]));
+    assert_eq!(touched, (reload::State::Hooked, 0));
+    let other = app.world.get::<reload::Hook>(other).unwrap();
+    assert_eq!(
+        (&other.state, other.generation()),
+        (&reload::State::Hooked, 0)
+    );
+    assert_eq!(hooked.get(), 2);
+
+    // Manual reloads are never skipped.
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    assert_eq!(app.world.get::<reload::Hook>(root).unwrap().generation(), 1);
+    assert_eq!(hooked.get(), 3);
+}
+
+/// Replace the scene of the hooked `root` by `edited`, reloading it with
+/// [`reload::AutoReloadPlugin`].
+fn reload_edited(app: &mut App, root: Entity, edited: Scene) -> (reload::State, u32) {
+    let handle = app.world.get::<Handle<Scene>>(root).unwrap().clone();
+    let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+    scenes.insert(&handle, edited);
+    settle(app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    (hook.state.clone(), hook.generation())
+}
+
+#[test]
+fn reload_edited_scene_when_skipping_unchanged() {
+    let mut app = app(reload::Plugin);
+    app.add_plugins(reload::AutoReloadPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {}).skip_unchanged_reloads();
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    let edited = reload_edited(&mut app, root, scene(&["Card"]));
+    assert_eq!(edited, (reload::State::Hooked, 1));
+}
+
+#[test]
+fn scene_id_kept_across_reloads_and_freed_on_despawn() {
+    let mut app = app(reload::Plugin);
+    app.add_plugins(SceneIdPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    let id = *app.world.get::<SceneId>(root).unwrap();
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(hook.state, reload::State::Hooked);
+    assert_eq!(hook.generation(), 1);
+    assert_eq!(app.world.get::<SceneId>(root), Some(&id));
+    assert_eq!(app.world.resource::<SceneIds>().get(id), Some(root));
+
+    app.world.entity_mut(root).despawn_recursive();
+    app.update();
+    assert_eq!(app.world.resource::<SceneIds>().get(id), None);
+    assert_eq!(app.world.resource::<SceneIds>().iter().count(), 0);
+}
+
+#[test]
+fn modified_scene_asset_must_reload() {
+    let mut app = app(reload::Plugin);
+    app.add_plugins(reload::AutoReloadPlugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let root = app
+        .world
+        .spawn(reload::SceneBundle::new(handle.clone(), hook))
+        .id();
+    settle(&mut app);
+    assert_eq!(
+        app.world.get::<reload::Hook>(root).unwrap().state,
+        reload::State::Hooked
+    );
+
+    app.world
+        .send_event(AssetEvent::<Scene>::Modified { id: handle.id() });
+    app.world.run_system_once(reload::reload_modified_scenes);
+    assert_eq!(
+        app.world.get::<reload::Hook>(root).unwrap().state,
+        reload::State::MustReload
+    );
+
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(hook.state, reload::State::Hooked);
+    assert_eq!(hook.generation(), 1);
+}
+
+#[test]
+fn request_reload_notifies_state_change() {
+    use reload::State::{Hooked, Loading, MustReload, Reloading};
+    use std::sync::{Arc, Mutex};
+
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&changes);
+    let hook = reload::Hook::new(|_, _, _, _| {})
+        .on_state_change(move |old, new| recorded.lock().unwrap().push((old, new)));
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    let mut hook = app.world.get_mut::<reload::Hook>(root).unwrap();
+    assert!(hook.request_reload());
+    assert_eq!(changes.lock().unwrap().last(), Some(&(Hooked, MustReload)));
+    settle(&mut app);
+    let expected = [
+        (Loading, Hooked),
+        (Hooked, MustReload),
+        (MustReload, Reloading),
+        (Reloading, Hooked),
+    ];
+    assert_eq!(*changes.lock().unwrap(), expected);
+}
+
+#[test]
+fn reload_hooked_only_once_hooked() {
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let bundle = reload::SceneBundle::new(handle, hook);
+    let root = app.world.spawn((bundle, Level(1))).id();
+    let is_hooked = |app: &mut App| app.world.run_system_once(reload::is_reload_hooked::<Level>);
+
+    assert!(!is_hooked(&mut app), "Loading");
+    update_until(&mut app, |world| {
+        world.get::<reload::Hook>(root).unwrap().state == reload::State::Hooked
+    });
+    assert!(is_hooked(&mut app), "Hooked");
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    assert!(!is_hooked(&mut app), "MustReload");
+    app.update();
+    let state = &app.world.get::<reload::Hook>(root).unwrap().state;
+    assert_eq!(*state, reload::State::Reloading);
+    assert!(!is_hooked(&mut app), "Reloading");
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[test]
+fn reload_restores_retained_components() {
+    let mut app = app(reload::Plugin);
+    app.register_type::<Health>();
+    let handle = add_scene(&mut app, &["Knight", "Squire"]);
+    let hook = reload::Hook::new(|_, cmds, _, _| {
+        cmds.insert(Health(10));
+    });
+    let hook = hook.retain::<Health>();
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    let mut healths = app.world.query::<(&Name, &mut Health)>();
+    for (name, mut health) in healths.iter_mut(&mut app.world) {
+        health.0 = if name.as_str() == "Knight" { 3 } else { 7 };
+    }
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+
+    assert_eq!(app.world.get::<reload::Hook>(root).unwrap().generation(), 1);
+    let mut healths = app.world.query::<(&Name, &Health)>();
+    let mut healths: Vec<_> = healths
+        .iter(&app.world)
+        .map(|(name, health)| (name.to_string(), health.0))
+        .collect();
+    healths.sort();
+    // The hook sets `Health(10)` on the respawned entities, then the saved values are restored.
+    assert_eq!(
+        healths,
+        [("Knight".to_owned(), 3), ("Squire".to_owned(), 7)]
+    );
+}
+
+#[test]
+fn reload_with_replaces_scene() {
+    let mut app = app(reload::Plugin);
+    let pile = add_scene(&mut app, &["Pile"]);
+    let deck = add_scene(&mut app, &["Ace", "King"]);
+    let hook = reload::Hook::new(|_, cmds, _, _| {
+        cmds.insert(Extra);
+    });
+    let root = app.world.spawn(reload::SceneBundle::new(pile, hook)).id();
+    settle(&mut app);
+    assert_eq!(child_names(&app, root), ["Pile"]);
+
+    let mut hook = app.world.get_mut::<reload::Hook>(root).unwrap();
+    hook.reload_with(deck.clone());
+    settle(&mut app);
+    assert_eq!(child_names(&app, root), ["Ace", "King"]);
+    assert_eq!(app.world.get::<Handle<Scene>>(root), Some(&deck));
+    assert_eq!(extra_count(&mut app), 2);
+
+    // A plain reload now reloads the scene passed to `reload_with`.
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(
+        (&hook.state, hook.generation()),
+        (&reload::State::Hooked, 2)
+    );
+    assert_eq!(child_names(&app, root), ["Ace", "King"]);
+    assert_eq!(app.world.get::<Handle<Scene>>(root), Some(&deck));
+    assert_eq!(extra_count(&mut app), 2);
+}
+
+#[test]
+fn on_delete_runs_before_on_despawn() {
+    use std::sync::{Arc, Mutex};
+
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile", "Card"]);
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let (on_delete, on_despawn) = (Arc::clone(&calls), Arc::clone(&calls));
+    let children = |cmds: &EntityCommands, world: &World| {
+        world.get::<Children>(cmds.id()).map_or(0, |c| c.len())
+    };
+    let hook = reload::Hook::new(|_, _, _, _| {})
+        .on_delete(move |cmds, world| {
+            on_delete
+                .lock()
+                .unwrap()
+                .push(("on_delete", children(cmds, world)));
+        })
+        .on_despawn(move |cmds, world| {
+            on_despawn
+                .lock()
+                .unwrap()
+                .push(("on_despawn", children(cmds, world)));
+        });
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustDelete;
+    app.update();
+    // Both run while the two scene entities are still there.
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [("on_delete", 2), ("on_despawn", 2)]
+    );
+    assert!(app.world.get_entity(root).is_none());
+}
+
+#[test]
+fn hook_callbacks_run_in_order() {
+    use std::sync::{Arc, Mutex};
+
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile", "Card", "Deck"]);
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let record = |call: &'static str| {
+        let calls = Arc::clone(&calls);
+        move |_: &mut EntityCommands, _: &World| calls.lock().unwrap().push(call.to_owned())
+    };
+    let hooked = Arc::clone(&calls);
+    let hook = reload::Hook::new(move |entity, _, _, _| {
+        let name = entity.get::<Name>().unwrap();
+        hooked.lock().unwrap().push(name.to_string());
+    })
+    .filter(|entity| entity.get::<Name>().map(Name::as_str) != Some("Deck"))
+    .before(record("before"))
+    .on_loaded(record("on_loaded"))
+    .on_despawn(record("on_despawn"));
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    let loaded = ["before", "Pile", "Card", "on_loaded"];
+    assert_eq!(*calls.lock().unwrap(), loaded);
+
+    calls.lock().unwrap().clear();
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    settle(&mut app);
+    let reloaded = ["on_despawn", "before", "Pile", "Card", "on_loaded"];
+    assert_eq!(*calls.lock().unwrap(), reloaded);
+}
+
+#[test]
+fn request_reload_command_ignored_while_reloading() {
+    use reload::ReloadSceneExt;
+
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let hook = reload::Hook::new(|_, _, _, _| {});
+    let root = app.world.spawn(reload::SceneBundle::new(handle, hook)).id();
+    settle(&mut app);
+    let request_reload = move |mut cmds: Commands| {
+        cmds.entity(root).request_reload();
+    };
+
+    app.world.get_mut::<reload::Hook>(root).unwrap().state = reload::State::MustReload;
+    app.update();
+    assert_eq!(
+        app.world.get::<reload::Hook>(root).unwrap().state,
+        reload::State::Reloading
+    );
+    app.world.run_system_once(request_reload);
+    assert_eq!(
+        app.world.get::<reload::Hook>(root).unwrap().state,
+        reload::State::Reloading
+    );
+    settle(&mut app);
+    let hook = app.world.get::<reload::Hook>(root).unwrap();
+    assert_eq!(
+        (&hook.state, hook.generation()),
+        (&reload::State::Hooked, 1)
+    );
+
+    // Once hooked again, the request is accepted.
+    app.world.run_system_once(request_reload);
+    assert_eq!(
+        app.world.get::<reload::Hook>(root).unwrap().state,
+        reload::State::MustReload
+    );
+}
+
+#[test]
+fn insert_on_scene_parent_only_with_parent() {
+    use std::sync::{Arc, Mutex};
+
+    let mut app = app(reload::Plugin);
+    let handle = add_scene(&mut app, &["Pile"]);
+    let inserted = Arc::new(Mutex::new(Vec::new()));
+    let new_hook = || {
+        let inserted = Arc::clone(&inserted);
+        reload::Hook::new_with_context(move |_, cmds, ctx| {
+            let on_parent = ctx.insert_on_scene_parent(cmds, Level(7));
+            inserted.lock().unwrap().push(on_parent);
+        })
+    };
+    let bundle = reload::SceneBundle::new(handle.clone(), new_hook());
+    let player = app.world.spawn_empty().id();
+    app.world.spawn(bundle).set_parent(player);
+    let orphan = reload::SceneBundle::new(handle, new_hook());
+    let orphan = app.world.spawn(orphan).id();
+    settle(&mut app);
+
+    assert_eq!(app.world.get::<Level>(player).map(|l| l.0), Some(7));
+    assert!(app.world.get::<Level>(orphan).is_none());
+    let mut inserted = inserted.lock().unwrap().clone();
+    inserted.sort_unstable();
+    assert_eq!(inserted, [false, true]);
+}